@@ -0,0 +1,79 @@
+use crate::interpreter::edition::Edition;
+use crate::interpreter::error::Result;
+use serde::Deserialize;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+/// Name of the project manifest looked up in the current directory.
+pub const MANIFEST_NAME: &str = "yun.toml";
+
+/// Project settings read from a `yun.toml`. Paths in it are relative to the
+/// directory the manifest is in.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Manifest {
+    /// Script the app runs.
+    pub entry: Option<PathBuf>,
+    pub edition: Option<Edition>,
+    /// Directories searched for modules not found next to the importer.
+    pub search_paths: Vec<PathBuf>,
+    /// Directories searched for images not found at the given path.
+    pub assets: Vec<PathBuf>,
+    pub window: WindowConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct WindowConfig {
+    pub title: String,
+    pub width: u32,
+    pub height: u32,
+    /// Whether to wait for vertical sync, the driver's default when unset.
+    pub vsync: Option<bool>,
+    /// Borderless fullscreen on the current monitor.
+    pub fullscreen: bool,
+    /// Minimum number of samples per pixel, no multisampling when unset.
+    pub msaa: Option<u8>,
+    /// RGBA color the frame is cleared to.
+    pub clear_color: [f32; 4],
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: "App".to_string(),
+            width: 800,
+            height: 480,
+            vsync: None,
+            fullscreen: false,
+            msaa: None,
+            clear_color: [0.0, 0.0, 0.0, 1.0],
+        }
+    }
+}
+
+impl Manifest {
+    /// Reads the manifest at `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let code = read_to_string(path)
+            .map_err(|err| format!("Can't read '{}': {}", path.display(), err))?;
+        let mut manifest: Manifest = toml::from_str(&code)
+            .map_err(|err| format!("Invalid manifest '{}': {}", path.display(), err))?;
+
+        let dir = path.parent().unwrap_or(Path::new(""));
+        manifest.entry = manifest.entry.map(|entry| dir.join(entry));
+        for path in manifest.search_paths.iter_mut().chain(&mut manifest.assets) {
+            *path = dir.join(&*path);
+        }
+        Ok(manifest)
+    }
+
+    /// The `yun.toml` of the current directory, if there is one.
+    pub fn discover() -> Result<Option<Self>> {
+        let path = Path::new(MANIFEST_NAME);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        Manifest::load(path).map(Some)
+    }
+}
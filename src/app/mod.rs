@@ -1,9 +1,11 @@
+use crate::app::manifest::Manifest;
 use crate::app::packet::Packet;
 use crate::interpreter::Interpreter;
 use crate::interpreter::event::InterpreterEvent;
 use crate::interpreter::object::Object;
 use crate::interpreter::object::callable::Callable;
-use crate::interpreter::render_statement::RenderStatement;
+use crate::interpreter::object::freeze::Freezable;
+use crate::interpreter::render_statement::{RenderStatement, pipeline_primitive};
 use crate::interpreter::render_statement::buffers_data::BuffersData;
 use crate::interpreter::render_statement::pipeline_data::AttributeLayouts;
 use crate::interpreter::render_statement::shader_generator::ShaderGenerator;
@@ -16,7 +18,7 @@ use crate::utils::projection_matrix;
 use cgmath::{Deg, Matrix4, Point3, Vector3};
 use glium::framebuffer::{DepthAttachment, SimpleFrameBuffer, ToDepthAttachment};
 use glium::glutin::surface::WindowSurface;
-use glium::index::{NoIndices, PrimitiveType};
+use glium::index::NoIndices;
 use glium::texture::{ClientFormat, CubeLayer, DepthCubemap, DepthTexture2d, RawImage2d};
 use glium::uniforms::{
     DynamicUniforms, MagnifySamplerFilter, MinifySamplerFilter, Sampler, SamplerWrapFunction,
@@ -44,7 +46,9 @@ use std::sync::mpsc::{Receiver, Sender};
 use std::sync::{Arc, Mutex, RwLock, mpsc};
 use std::time::Duration;
 
+pub mod manifest;
 pub mod packet;
+pub mod window;
 
 #[derive(Debug)]
 pub enum AppEvent {
@@ -96,6 +100,7 @@ pub struct App {
     shadow_textures_buffer: Arc<RwLock<Vec<(String, Sampler<'static, DepthCubemap>)>>>,
     shadow_program: Program,
     shadow_size: u32,
+    clear_color: (f32, f32, f32, f32),
 }
 
 impl App {
@@ -104,6 +109,7 @@ impl App {
         display: Arc<Display<WindowSurface>>,
         proxy: EventLoopProxy<InterpreterEvent>,
         path: PathBuf,
+        manifest: &Manifest,
     ) -> Self {
         let path_arc = rc!(path.clone());
         let (tx, rx) = mpsc::channel();
@@ -134,7 +140,12 @@ impl App {
             )
             .unwrap(),
             display,
-            interpreter: rc!(Mutex::new(Interpreter::new(proxy.clone(), path.clone()))),
+            interpreter: rc!(Mutex::new(
+                Interpreter::new(proxy.clone(), path.clone())
+                    .with_edition(manifest.edition.unwrap_or_default())
+                    .with_search_paths(manifest.search_paths.clone())
+                    .with_asset_paths(manifest.assets.clone())
+            )),
             render_statement: vec![],
             uniform_generator: rc!(RwLock::new(UniformGenerator::new())),
             path: path_arc,
@@ -151,6 +162,7 @@ impl App {
             shadow_textures_buffer: Arc::new(Default::default()),
             lights: Arc::new(Default::default()),
             shadow_size: 16,
+            clear_color: manifest.window.clear_color.into(),
         }
     }
 }
@@ -223,7 +235,6 @@ impl ApplicationHandler<InterpreterEvent> for App {
         const UNIFORM: &str = "uniform";
         const LAYOUT: &str = "layout";
         const DATA: &str = "data";
-        const PRIMITIVE: &str = "primitive";
         const VERTEX_SHADER: &str = "vertex";
         const FRAGMENT_SHADER: &str = "fragment";
         const LIGHTS: &str = "lights";
@@ -239,10 +250,10 @@ impl ApplicationHandler<InterpreterEvent> for App {
                 let shader_cache = self.shader_cache.clone();
                 let lights_ = self.lights.clone();
                 list.read().unwrap().par_iter().for_each(|elm| {
-                    let Some(pipeline) = elm.get_field(Number(0.0)) else {
+                    let Ok(pipeline) = elm.get_field(Number(0.0)) else {
                         return;
                     };
-                    let Some(vertex) = elm.get_field(Number(1.0)) else {
+                    let Ok(vertex) = elm.get_field(Number(1.0)) else {
                         return;
                     };
 
@@ -257,7 +268,7 @@ impl ApplicationHandler<InterpreterEvent> for App {
                         vec_vertex_data.read().unwrap().get(i).unwrap().clone()
                     } else {
                         let layout_list = match vertex.get_field(ObjString(LAYOUT.into())) {
-                            Some(List(layout)) => layout,
+                            Ok(List(layout)) => layout,
                             _ => return,
                         };
 
@@ -281,7 +292,7 @@ impl ApplicationHandler<InterpreterEvent> for App {
                         }
 
                         let data_list = match vertex.get_field(ObjString(DATA.into())) {
-                            Some(List(data)) => data,
+                            Ok(List(data)) => data,
                             _ => return,
                         };
 
@@ -310,11 +321,11 @@ impl ApplicationHandler<InterpreterEvent> for App {
 
                     let uniform = pipeline
                         .get_field(ObjString(UNIFORM.into()))
-                        .unwrap_or(Dictionary(rc!(RwLock::new(HashMap::new()))));
+                        .unwrap_or(Dictionary(rc!(Freezable::new(HashMap::new()))));
 
                     let raw_attrs_ = match pipeline.get_field(ObjString(ATTRIBUTES.into())) {
-                        Some(obj) => obj,
-                        _ => Dictionary(rc!(RwLock::new(HashMap::new()))),
+                        Ok(obj) => obj,
+                        _ => Dictionary(rc!(Freezable::new(HashMap::new()))),
                     };
 
                     let attrs_layout = if vec_attrs.read().unwrap().contains(&raw_attrs_) {
@@ -329,7 +340,7 @@ impl ApplicationHandler<InterpreterEvent> for App {
                     } else {
                         let raw_attrs = match raw_attrs_.clone() {
                             Dictionary(m) => m.clone(),
-                            _ => rc!(RwLock::new(HashMap::new())),
+                            _ => rc!(Freezable::new(HashMap::new())),
                         };
 
                         let mut attrs_in = HashMap::new();
@@ -361,15 +372,16 @@ impl ApplicationHandler<InterpreterEvent> for App {
                         attrs
                     };
 
-                    let mut uniforms = uniform_generator
-                        .write()
-                        .unwrap()
-                        .generate_uniforms(&uniform)
-                        .expect("Failed to generate uniforms");
+                    // Checked by `render` before the event was sent.
+                    let Ok(mut uniforms) =
+                        uniform_generator.write().unwrap().generate_uniforms(&uniform)
+                    else {
+                        return;
+                    };
 
                     let lights = match pipeline.get_field(ObjString(LIGHTS.into())) {
-                        Some(Dictionary(lights)) => lights,
-                        _ => rc!(RwLock::new(HashMap::new())),
+                        Ok(Dictionary(lights)) => lights,
+                        _ => rc!(Freezable::new(HashMap::new())),
                     };
 
                     let mut light_data = Vec::new();
@@ -423,21 +435,21 @@ impl ApplicationHandler<InterpreterEvent> for App {
                         ));
                     }
 
-                    let primitive = match pipeline.get_field(ObjString(PRIMITIVE.into())) {
-                        Some(Object::String(m)) => m,
-                        _ => "triangleStrip".into(),
+                    // Checked by `render` before the event was sent.
+                    let Ok(primitive_type) = pipeline_primitive(&pipeline) else {
+                        return;
                     };
 
                     //println!("DATA: {:?}", attrs_in);
 
                     let vertex_shader = match pipeline.get_field(ObjString(VERTEX_SHADER.into())) {
-                        Some(Object::String(m)) => Some(m),
+                        Ok(Object::String(m)) => Some(m),
                         _ => None,
                     };
 
                     let fragment_shader =
                         match pipeline.get_field(ObjString(FRAGMENT_SHADER.into())) {
-                            Some(Object::String(m)) => Some(m),
+                            Ok(Object::String(m)) => Some(m),
                             _ => None,
                         };
 
@@ -499,13 +511,7 @@ impl ApplicationHandler<InterpreterEvent> for App {
                         fragment_shader: frag,
                         buffer_data: BuffersData { data, layout },
                         uniforms,
-                        primitive_type: match primitive.as_str() {
-                            "points" => PrimitiveType::Points,
-                            "lineStrip" => PrimitiveType::LineStrip,
-                            "triangleStrip" => PrimitiveType::TriangleStrip,
-                            "triangles" => PrimitiveType::TrianglesList,
-                            _ => PrimitiveType::TriangleStrip,
-                        },
+                        primitive_type,
                         light_names,
                     });
                 });
@@ -571,16 +577,19 @@ impl ApplicationHandler<InterpreterEvent> for App {
             WindowEvent::RedrawRequested => {
                 let mut target = self.display.draw();
                 let mut tex_ref_buffer = vec![];
-                target.clear_color_and_depth((0.0, 0.0, 0.0, 1.0), 1.0);
+                target.clear_color_and_depth(self.clear_color, 1.0);
 
 
                 for stmt in &self.render_statement {
                     let mut uniforms = DynamicUniforms::new();
                     for (key, uniform) in &stmt.uniforms {
                         match uniform {
-                            UniformValueWrapper::Float(num) => uniforms.add(&key, num),
-                            UniformValueWrapper::Vec3(vec) => uniforms.add(&key, vec),
-                            UniformValueWrapper::Mat4(mat) => uniforms.add(&key, mat),
+                            UniformValueWrapper::Float(num) => uniforms.add(key, num),
+                            UniformValueWrapper::Vec2(vec) => uniforms.add(key, vec),
+                            UniformValueWrapper::Vec3(vec) => uniforms.add(key, vec),
+                            UniformValueWrapper::Vec4(vec) => uniforms.add(key, vec),
+                            UniformValueWrapper::Mat3(mat) => uniforms.add(key, mat),
+                            UniformValueWrapper::Mat4(mat) => uniforms.add(key, mat),
                             UniformValueWrapper::Sampler2D(img) => {
                                 if let Some((i, (_, _))) = self
                                     .tex_buffer
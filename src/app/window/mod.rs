@@ -0,0 +1,77 @@
+use crate::app::manifest::WindowConfig;
+use crate::interpreter::error::Result;
+use glium::Display;
+use glium::glutin::config::{ConfigTemplateBuilder, GlConfig};
+use glium::glutin::context::ContextAttributesBuilder;
+use glium::glutin::display::{GetGlDisplay, GlDisplay};
+use glium::glutin::prelude::NotCurrentGlContext;
+use glium::glutin::surface::{GlSurface, SurfaceAttributesBuilder, SwapInterval, WindowSurface};
+use glium::winit::dpi::PhysicalSize;
+use glium::winit::event_loop::EventLoop;
+use glium::winit::raw_window_handle::HasWindowHandle;
+use glium::winit::window::{Fullscreen, Window};
+use glutin_winit::DisplayBuilder;
+use std::num::NonZeroU32;
+
+/// Opens the window and its OpenGL display, like glium's
+/// `SimpleWindowBuilder` but honouring the sample count and vsync.
+pub fn build<T>(
+    event_loop: &EventLoop<T>,
+    config: &WindowConfig,
+) -> Result<(Window, Display<WindowSurface>)> {
+    let attributes = Window::default_attributes()
+        .with_title(&config.title)
+        .with_inner_size(PhysicalSize::new(config.width, config.height))
+        .with_fullscreen(config.fullscreen.then_some(Fullscreen::Borderless(None)));
+    let mut template = ConfigTemplateBuilder::new();
+    if let Some(msaa) = config.msaa {
+        template = template.with_multisampling(msaa);
+    }
+
+    // Configs have at least the requested samples, take the closest one.
+    let (window, gl_config) = DisplayBuilder::new()
+        .with_window_attributes(Some(attributes))
+        .build(event_loop, template, |configs| {
+            configs.min_by_key(|config| config.num_samples()).unwrap()
+        })
+        .map_err(|err| format!("Can't create window: {}", err))?;
+    let window = window.ok_or("Can't create window".to_string())?;
+    let handle = window
+        .window_handle()
+        .map_err(|err| err.to_string())?
+        .as_raw();
+
+    let (width, height): (u32, u32) = window.inner_size().into();
+    let surface_attributes = SurfaceAttributesBuilder::<WindowSurface>::new().build(
+        handle,
+        NonZeroU32::new(width).unwrap_or(NonZeroU32::MIN),
+        NonZeroU32::new(height).unwrap_or(NonZeroU32::MIN),
+    );
+    let context_attributes = ContextAttributesBuilder::new().build(Some(handle));
+    let gl_display = gl_config.display();
+    let (surface, context) = unsafe {
+        let surface = gl_display
+            .create_window_surface(&gl_config, &surface_attributes)
+            .map_err(|err| err.to_string())?;
+        let context = gl_display
+            .create_context(&gl_config, &context_attributes)
+            .map_err(|err| err.to_string())?;
+        (surface, context)
+    };
+    let context = context
+        .make_current(&surface)
+        .map_err(|err| err.to_string())?;
+
+    if let Some(vsync) = config.vsync {
+        let interval = match vsync {
+            true => SwapInterval::Wait(NonZeroU32::MIN),
+            false => SwapInterval::DontWait,
+        };
+        surface
+            .set_swap_interval(&context, interval)
+            .map_err(|err| err.to_string())?;
+    }
+
+    let display = Display::from_context_surface(context, surface).map_err(|err| err.to_string())?;
+    Ok((window, display))
+}
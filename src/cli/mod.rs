@@ -1,13 +1,90 @@
 use clap::Parser;
 use std::path::PathBuf;
+use yun_gl_lib::app::manifest::Manifest;
+use yun_gl_lib::interpreter::edition::Edition;
+use yun_gl_lib::interpreter::error::Result;
 
+/// Flags override the settings of the `yun.toml` in the current directory.
 #[derive(Clone, Debug, Parser)]
 pub struct Cli {
-    path: PathBuf,
+    /// Script to run, the manifest's entry by default
+    path: Option<PathBuf>,
+    /// Language edition to run the script with (2025 or 2026)
+    #[arg(long)]
+    edition: Option<Edition>,
+    /// Directory to search for modules, may be given several times
+    #[arg(short = 'I', long = "search-path", value_name = "DIR")]
+    search_paths: Vec<PathBuf>,
+    /// Directory to search for images, may be given several times
+    #[arg(long = "asset", value_name = "DIR")]
+    assets: Vec<PathBuf>,
+    /// Window title
+    #[arg(long)]
+    title: Option<String>,
+    /// Window width in pixels
+    #[arg(long)]
+    width: Option<u32>,
+    /// Window height in pixels
+    #[arg(long)]
+    height: Option<u32>,
+    /// Wait for vertical sync
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, default_missing_value = "true")]
+    vsync: Option<bool>,
+    /// Borderless fullscreen on the current monitor
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, default_missing_value = "true")]
+    fullscreen: Option<bool>,
+    /// Minimum number of samples per pixel
+    #[arg(long, value_name = "SAMPLES")]
+    msaa: Option<u8>,
+    /// Color the frame is cleared to
+    #[arg(long, value_name = "R,G,B,A", value_parser = parse_color)]
+    clear_color: Option<[f32; 4]>,
+}
+
+fn parse_color(s: &str) -> std::result::Result<[f32; 4], String> {
+    let channels = s
+        .split(',')
+        .map(|channel| channel.trim().parse::<f32>().map_err(|err| err.to_string()))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    channels
+        .try_into()
+        .map_err(|_| "expected four comma separated numbers".to_string())
 }
 
 impl Cli {
-    pub fn get_path(&self) -> &PathBuf {
-        &self.path
+    /// The manifest of the current directory, or the defaults without one,
+    /// with the flags applied. Module search paths are the `--search-path`
+    /// flags, then the manifest's, then the entries of the `YUN_PATH`
+    /// environment variable; asset directories are ordered the same way.
+    pub fn get_manifest(&self) -> Result<Manifest> {
+        let mut manifest = Manifest::discover()?.unwrap_or_default();
+        if let Some(path) = &self.path {
+            manifest.entry = Some(path.clone());
+        }
+        if let Some(edition) = self.edition {
+            manifest.edition = Some(edition);
+        }
+
+        let mut search_paths = self.search_paths.clone();
+        search_paths.append(&mut manifest.search_paths);
+        if let Some(yun_path) = std::env::var_os("YUN_PATH") {
+            search_paths.extend(std::env::split_paths(&yun_path));
+        }
+        manifest.search_paths = search_paths;
+        let mut assets = self.assets.clone();
+        assets.append(&mut manifest.assets);
+        manifest.assets = assets;
+
+        let window = &mut manifest.window;
+        if let Some(title) = &self.title {
+            window.title = title.clone();
+        }
+        window.width = self.width.unwrap_or(window.width);
+        window.height = self.height.unwrap_or(window.height);
+        window.vsync = self.vsync.or(window.vsync);
+        window.fullscreen = self.fullscreen.unwrap_or(window.fullscreen);
+        window.msaa = self.msaa.or(window.msaa);
+        window.clear_color = self.clear_color.unwrap_or(window.clear_color);
+        Ok(manifest)
     }
 }
@@ -1,18 +1,21 @@
 use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::ast::param::Param;
 use crate::interpreter::ast::stmt::Stmt;
 use crate::interpreter::scanner::token::Token;
 use crate::utils::next_id;
 
+type ExtractedAnonFun<'a, T> = (u64, &'a Token, &'a [Param<T>], Vec<&'a dyn Stmt<T>>);
+
 #[derive(Clone)]
 pub struct AnonFun<T: 'static> {
     id: u64,
     token: Token,
-    params: Vec<Token>,
+    params: Vec<Param<T>>,
     body: Vec<Box<dyn Stmt<T>>>,
 }
 
 impl<T> AnonFun<T> {
-    pub fn new(token: Token, params: Vec<Token>, body: Vec<Box<dyn Stmt<T>>>) -> Self {
+    pub fn new(token: Token, params: Vec<Param<T>>, body: Vec<Box<dyn Stmt<T>>>) -> Self {
         Self {
             id: next_id(),
             token,
@@ -21,7 +24,7 @@ impl<T> AnonFun<T> {
         }
     }
 
-    pub fn extract(&self) -> (u64, &Token, &[Token], Vec<&dyn Stmt<T>>) {
+    pub fn extract(&self) -> ExtractedAnonFun<'_, T> {
         (
             self.id,
             &self.token,
@@ -3,18 +3,40 @@ use crate::interpreter::scanner::token::Token;
 use crate::utils::next_id;
 use std::ops::Deref;
 
+#[derive(Clone)]
+pub enum AssignOp {
+    /// `x = value`
+    Plain,
+    /// `x += value` or `++x`, evaluates to the updated value.
+    Compound(Token),
+    /// `x++`, evaluates to the value before the update.
+    Postfix(Token),
+}
+
+impl AssignOp {
+    pub fn is_plain(&self) -> bool {
+        matches!(self, AssignOp::Plain)
+    }
+}
+
 #[derive(Clone)]
 pub struct Assign<T: 'static> {
     id: u64,
     token: Token,
+    op: AssignOp,
     value: Box<dyn Expr<T>>,
 }
 
 impl<T> Assign<T> {
     pub fn new(token: Token, value: Box<dyn Expr<T>>) -> Self {
+        Self::with_op(token, AssignOp::Plain, value)
+    }
+
+    pub fn with_op(token: Token, op: AssignOp, value: Box<dyn Expr<T>>) -> Self {
         Self {
             id: next_id(),
             token,
+            op,
             value,
         }
     }
@@ -23,6 +45,10 @@ impl<T> Assign<T> {
         self.token.clone()
     }
 
+    pub fn get_op(&self) -> &AssignOp {
+        &self.op
+    }
+
     pub fn get_value(&self) -> &dyn Expr<T> {
         self.value.deref()
     }
@@ -9,6 +9,8 @@ pub struct Call<T: 'static> {
     callable: Box<dyn Expr<T>>,
     parens: Token,
     args: Vec<Box<dyn Expr<T>>>,
+    named: Vec<(Token, Box<dyn Expr<T>>)>,
+    optional: bool,
 }
 
 impl<T> Call<T> {
@@ -18,9 +20,38 @@ impl<T> Call<T> {
             callable,
             parens,
             args,
+            named: vec![],
+            optional: false,
         }
     }
 
+    /// Call with `name: value` arguments following the positional ones.
+    pub fn with_named(
+        callable: Box<dyn Expr<T>>,
+        parens: Token,
+        args: Vec<Box<dyn Expr<T>>>,
+        named: Vec<(Token, Box<dyn Expr<T>>)>,
+    ) -> Self {
+        Self {
+            id: next_id(),
+            callable,
+            parens,
+            args,
+            named,
+            optional: false,
+        }
+    }
+
+    /// `f?.()`: evaluates to nil instead of failing when the callee is nil.
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
     pub fn get_callable(&self) -> &dyn Expr<T> {
         self.callable.deref()
     }
@@ -29,6 +60,13 @@ impl<T> Call<T> {
         self.args.iter().map(|arg| arg.deref()).collect()
     }
 
+    pub fn get_named(&self) -> Vec<(&Token, &dyn Expr<T>)> {
+        self.named
+            .iter()
+            .map(|(name, arg)| (name, arg.deref()))
+            .collect()
+    }
+
     pub fn get_token(&self) -> Token {
         self.parens.clone()
     }
@@ -14,6 +14,7 @@ pub struct Get<T: 'static> {
     id: u64,
     ty: GetType<T>,
     object: Box<dyn Expr<T>>,
+    optional: bool,
 }
 
 impl<T> Get<T> {
@@ -22,9 +23,21 @@ impl<T> Get<T> {
             id: next_id(),
             ty,
             object,
+            optional: false,
         }
     }
 
+    /// `a?.b` / `a?[i]`: evaluates to nil instead of failing when the
+    /// object is nil.
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
     pub fn extract(&self) -> (&GetType<T>, &dyn Expr<T>) {
         (&self.ty, self.object.deref())
     }
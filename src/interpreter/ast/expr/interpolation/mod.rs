@@ -0,0 +1,40 @@
+use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::scanner::token::Token;
+use crate::utils::next_id;
+
+/// `"text ${expr} text"`, lowered into the literal segments and embedded
+/// expressions in source order.
+#[derive(Clone)]
+pub struct Interpolation<T: 'static> {
+    id: u64,
+    token: Token,
+    parts: Vec<Box<dyn Expr<T>>>,
+}
+
+impl<T> Interpolation<T> {
+    pub fn new(token: Token, parts: Vec<Box<dyn Expr<T>>>) -> Self {
+        Self {
+            id: next_id(),
+            token,
+            parts,
+        }
+    }
+
+    pub fn get_token(&self) -> Token {
+        self.token.clone()
+    }
+
+    pub fn get_parts(&self) -> Vec<&dyn Expr<T>> {
+        self.parts.iter().map(AsRef::as_ref).collect()
+    }
+}
+
+impl<T: 'static + Clone> Expr<T> for Interpolation<T> {
+    fn accept(&self, visitor: &mut dyn ExprVisitor<T>) -> T {
+        visitor.visit_interpolation(self)
+    }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
@@ -0,0 +1,83 @@
+use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::ast::pattern::Pattern;
+use crate::interpreter::ast::stmt::Stmt;
+use crate::interpreter::scanner::token::Token;
+use crate::utils::next_id;
+use std::ops::Deref;
+
+#[derive(Clone)]
+pub enum ArmBody<T: 'static> {
+    Expr(Box<dyn Expr<T>>),
+    /// `=> { ... }`, evaluates to `nil`.
+    Block(Vec<Box<dyn Stmt<T>>>),
+}
+
+#[derive(Clone)]
+pub struct MatchArm<T: 'static> {
+    pattern: Pattern,
+    guard: Option<Box<dyn Expr<T>>>,
+    body: ArmBody<T>,
+}
+
+impl<T> MatchArm<T> {
+    pub fn new(pattern: Pattern, guard: Option<Box<dyn Expr<T>>>, body: ArmBody<T>) -> Self {
+        Self {
+            pattern,
+            guard,
+            body,
+        }
+    }
+
+    pub fn get_pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    pub fn get_guard(&self) -> Option<&dyn Expr<T>> {
+        self.guard.as_deref()
+    }
+
+    pub fn get_body(&self) -> &ArmBody<T> {
+        &self.body
+    }
+}
+
+#[derive(Clone)]
+pub struct Match<T: 'static> {
+    id: u64,
+    keyword: Token,
+    subject: Box<dyn Expr<T>>,
+    arms: Vec<MatchArm<T>>,
+}
+
+impl<T> Match<T> {
+    pub fn new(keyword: Token, subject: Box<dyn Expr<T>>, arms: Vec<MatchArm<T>>) -> Self {
+        Self {
+            id: next_id(),
+            keyword,
+            subject,
+            arms,
+        }
+    }
+
+    pub fn get_keyword(&self) -> Token {
+        self.keyword.clone()
+    }
+
+    pub fn get_subject(&self) -> &dyn Expr<T> {
+        self.subject.deref()
+    }
+
+    pub fn get_arms(&self) -> &[MatchArm<T>] {
+        &self.arms
+    }
+}
+
+impl<T: 'static + Clone> Expr<T> for Match<T> {
+    fn accept(&self, visitor: &mut dyn ExprVisitor<T>) -> T {
+        visitor.visit_match(self)
+    }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
@@ -4,9 +4,11 @@ use crate::interpreter::ast::expr::binary::Binary;
 use crate::interpreter::ast::expr::call::Call;
 use crate::interpreter::ast::expr::get::Get;
 use crate::interpreter::ast::expr::grouping::Grouping;
+use crate::interpreter::ast::expr::interpolation::Interpolation;
 use crate::interpreter::ast::expr::list::List;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::expr::logical::Logical;
+use crate::interpreter::ast::expr::match_expr::Match;
 use crate::interpreter::ast::expr::object::Obj;
 use crate::interpreter::ast::expr::self_expr::SelfExpr;
 use crate::interpreter::ast::expr::set::Set;
@@ -21,9 +23,11 @@ pub mod binary;
 pub mod call;
 pub mod get;
 pub mod grouping;
+pub mod interpolation;
 pub mod list;
 pub mod literal;
 pub mod logical;
+pub mod match_expr;
 pub mod object;
 pub mod self_expr;
 pub mod set;
@@ -47,6 +51,8 @@ pub trait ExprVisitor<T> {
     fn visit_list(&mut self, list: &List<T>) -> T;
     fn visit_object(&mut self, object: &Obj<T>) -> T;
     fn visit_anon(&mut self, anon: &AnonFun<T>) -> T;
+    fn visit_interpolation(&mut self, interpolation: &Interpolation<T>) -> T;
+    fn visit_match(&mut self, match_expr: &Match<T>) -> T;
 }
 
 pub trait Expr<T>: Downcast + CloneExpr<T> + Send + Sync + 'static {
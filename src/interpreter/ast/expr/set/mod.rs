@@ -1,9 +1,17 @@
+use crate::interpreter::ast::expr::assignment::AssignOp;
 use crate::interpreter::ast::expr::get::GetType;
 use crate::interpreter::ast::expr::{Expr, ExprVisitor};
 use crate::interpreter::scanner::token::Token;
 use crate::utils::next_id;
 use std::ops::Deref;
 
+type ExtractedSet<'a, T> = (
+    &'a SetType<T>,
+    &'a dyn Expr<T>,
+    &'a AssignOp,
+    &'a dyn Expr<T>,
+);
+
 #[derive(Clone)]
 pub enum SetType<T: 'static> {
     Name(Token),
@@ -24,21 +32,32 @@ pub struct Set<T: 'static> {
     id: u64,
     ty: SetType<T>,
     obj: Box<dyn Expr<T>>,
+    op: AssignOp,
     value: Box<dyn Expr<T>>,
 }
 
 impl<T> Set<T> {
     pub fn new(ty: SetType<T>, obj: Box<dyn Expr<T>>, value: Box<dyn Expr<T>>) -> Self {
+        Self::with_op(ty, obj, AssignOp::Plain, value)
+    }
+
+    pub fn with_op(
+        ty: SetType<T>,
+        obj: Box<dyn Expr<T>>,
+        op: AssignOp,
+        value: Box<dyn Expr<T>>,
+    ) -> Self {
         Self {
             id: next_id(),
             ty,
             obj,
+            op,
             value,
         }
     }
 
-    pub fn extract(&self) -> (&SetType<T>, &dyn Expr<T>, &dyn Expr<T>) {
-        (&self.ty, self.obj.deref(), self.value.deref())
+    pub fn extract(&self) -> ExtractedSet<'_, T> {
+        (&self.ty, self.obj.deref(), &self.op, self.value.deref())
     }
 }
 
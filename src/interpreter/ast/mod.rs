@@ -1,2 +1,4 @@
 pub mod expr;
+pub mod param;
+pub mod pattern;
 pub mod stmt;
@@ -0,0 +1,53 @@
+use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::scanner::token::Token;
+
+/// A function parameter: `name`, `name = default` or `...name`.
+#[derive(Clone)]
+pub struct Param<T: 'static> {
+    name: Token,
+    default: Option<Box<dyn Expr<T>>>,
+    variadic: bool,
+}
+
+impl<T> Param<T> {
+    pub fn new(name: Token) -> Self {
+        Self {
+            name,
+            default: None,
+            variadic: false,
+        }
+    }
+
+    pub fn with_default(name: Token, default: Box<dyn Expr<T>>) -> Self {
+        Self {
+            name,
+            default: Some(default),
+            variadic: false,
+        }
+    }
+
+    /// The trailing `...name` parameter collecting extra arguments into a list.
+    pub fn variadic(name: Token) -> Self {
+        Self {
+            name,
+            default: None,
+            variadic: true,
+        }
+    }
+
+    pub fn get_name(&self) -> &Token {
+        &self.name
+    }
+
+    pub fn get_default(&self) -> Option<&dyn Expr<T>> {
+        self.default.as_deref()
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.default.is_none() && !self.variadic
+    }
+}
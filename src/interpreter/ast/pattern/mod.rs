@@ -0,0 +1,330 @@
+use crate::interpreter::Interpreter;
+use crate::interpreter::error::Result;
+use crate::interpreter::object::Object;
+use crate::interpreter::object::freeze::Freezable;
+use crate::interpreter::scanner::token::Token;
+use crate::rc;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Names that stand for a type check instead of a binding when used as a
+/// pattern.
+pub const TYPE_PATTERNS: [&str; 13] = [
+    "number",
+    "string",
+    "boolean",
+    "list",
+    "dictionary",
+    "range",
+    "function",
+    "vec2",
+    "vec3",
+    "vec4",
+    "mat3",
+    "mat4",
+    "quat",
+];
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// `_`
+    Wildcard(Token),
+    /// `1`, `"a"`, `true`, `nil`
+    Literal(Token, Object),
+    /// `number`, `string`, ...
+    Type(Token),
+    /// `name`
+    Binding(Token),
+    /// `[a, b]`, `[a, ..]`, `[a, ..rest]`
+    List(Token, Vec<Pattern>, Option<Rest>),
+    /// `{x, y}`, `{x: [a, b]}`
+    Dictionary(Token, Vec<(Token, Pattern)>),
+    /// `Shape.Empty`, `Shape.Circle(r)`; the variant name, then the enum
+    /// name. Without a payload list any payload matches.
+    Variant(Token, Token, Option<Vec<Pattern>>),
+}
+
+/// The `..` tail of a list pattern, optionally bound to a name.
+#[derive(Debug, Clone)]
+pub struct Rest(pub Option<Token>);
+
+impl Pattern {
+    pub fn get_token(&self) -> &Token {
+        match self {
+            Pattern::Wildcard(token)
+            | Pattern::Literal(token, _)
+            | Pattern::Type(token)
+            | Pattern::Binding(token)
+            | Pattern::List(token, ..)
+            | Pattern::Dictionary(token, _)
+            | Pattern::Variant(token, ..) => token,
+        }
+    }
+
+    /// Every name the pattern binds, in source order.
+    pub fn bindings(&self) -> Vec<&Token> {
+        match self {
+            Pattern::Binding(name) => vec![name],
+            Pattern::List(_, items, rest) => {
+                let mut names: Vec<_> = items.iter().flat_map(Pattern::bindings).collect();
+                if let Some(Rest(Some(name))) = rest {
+                    names.push(name);
+                }
+                names
+            }
+            Pattern::Dictionary(_, entries) => entries
+                .iter()
+                .flat_map(|(_, pattern)| pattern.bindings())
+                .collect(),
+            Pattern::Variant(_, _, Some(items)) => {
+                items.iter().flat_map(Pattern::bindings).collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Whether every value matching `other` also matches `self`, so an arm
+    /// with `other` placed after an unguarded `self` can never be reached.
+    /// Conservative: only simple cases are detected.
+    pub fn covers(&self, other: &Pattern) -> bool {
+        match (self, other) {
+            (Pattern::Wildcard(_) | Pattern::Binding(_), _) => true,
+            (Pattern::Literal(_, a), Pattern::Literal(_, b)) => a == b,
+            (Pattern::Type(a), Pattern::Type(b)) => a.get_lexeme() == b.get_lexeme(),
+            (Pattern::Type(ty), Pattern::Literal(_, value)) => {
+                Pattern::is_of_type(ty.get_lexeme(), value)
+            }
+            (Pattern::Type(ty), Pattern::List(..)) => ty.get_lexeme() == "list",
+            (Pattern::Variant(a, enum_a, items_a), Pattern::Variant(b, enum_b, items_b)) => {
+                a.get_lexeme() == b.get_lexeme()
+                    && enum_a.get_lexeme() == enum_b.get_lexeme()
+                    && match (items_a, items_b) {
+                        (None, _) => true,
+                        (Some(items_a), Some(items_b)) => {
+                            items_a.len() == items_b.len()
+                                && items_a.iter().zip(items_b).all(|(a, b)| a.covers(b))
+                        }
+                        (Some(items_a), None) => items_a
+                            .iter()
+                            .all(|item| matches!(item, Pattern::Wildcard(_) | Pattern::Binding(_))),
+                    }
+            }
+            _ => false,
+        }
+    }
+
+    /// Tests `value` against the pattern, pushing the bound names on success.
+    /// On failure `bindings` may hold a partial set and should be discarded.
+    /// Instance properties are read through their getters, whose errors are
+    /// returned.
+    pub fn matches(
+        &self,
+        interpreter: &mut Interpreter,
+        value: &Object,
+        bindings: &mut Vec<(Token, Object)>,
+    ) -> Result<bool> {
+        match self {
+            Pattern::Wildcard(_) => Ok(true),
+            Pattern::Literal(_, literal) => Ok(literal == value),
+            Pattern::Type(ty) => Ok(Pattern::is_of_type(ty.get_lexeme(), value)),
+            Pattern::Binding(name) => {
+                bindings.push((name.clone(), value.clone()));
+                Ok(true)
+            }
+            Pattern::List(_, items, rest) => {
+                let Object::List(list) = value.inner() else {
+                    return Ok(false);
+                };
+                let list = list.read().unwrap().clone();
+                let fits = match rest {
+                    Some(_) => list.len() >= items.len(),
+                    None => list.len() == items.len(),
+                };
+                if !fits || !Pattern::matches_all(interpreter, items, &list, bindings)? {
+                    return Ok(false);
+                }
+                if let Some(Rest(Some(name))) = rest {
+                    let tail = list[items.len()..].to_vec();
+                    bindings.push((name.clone(), Object::List(rc!(Freezable::new(tail)))));
+                }
+                Ok(true)
+            }
+            Pattern::Dictionary(_, entries) => {
+                for (key, pattern) in entries {
+                    let field = match value.inner() {
+                        Object::Dictionary(map) => {
+                            map.read().unwrap().get(key.get_lexeme()).cloned()
+                        }
+                        Object::Instance(instance) => {
+                            instance.find_property(interpreter, key.get_lexeme())?
+                        }
+                        _ => None,
+                    };
+                    let Some(field) = field else {
+                        return Ok(false);
+                    };
+                    if !pattern.matches(interpreter, &field, bindings)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Pattern::Variant(name, enum_name, items) => {
+                let Object::Variant(variant) = value.inner() else {
+                    return Ok(false);
+                };
+                if variant.get_name() != name.get_lexeme()
+                    || variant.get_enum_name() != enum_name.get_lexeme()
+                {
+                    return Ok(false);
+                }
+                match items {
+                    None => Ok(true),
+                    Some(items) => {
+                        let values = variant.get_values();
+                        Ok(items.len() == values.len()
+                            && Pattern::matches_all(interpreter, items, values, bindings)?)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Matches `values` pairwise against `items`, stopping at the first
+    /// mismatch.
+    fn matches_all(
+        interpreter: &mut Interpreter,
+        items: &[Pattern],
+        values: &[Object],
+        bindings: &mut Vec<(Token, Object)>,
+    ) -> Result<bool> {
+        for (item, value) in items.iter().zip(values) {
+            if !item.matches(interpreter, value, bindings)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn is_of_type(name: &str, value: &Object) -> bool {
+        if let Object::Math(math) = value.inner() {
+            return math.get_type() == name;
+        }
+        matches!(
+            (name, value.inner()),
+            ("number", Object::Number(_))
+                | ("string", Object::String(_))
+                | ("boolean", Object::Bool(_))
+                | ("list", Object::List(_))
+                | ("dictionary", Object::Dictionary(_))
+                | ("range", Object::Range(..))
+                | ("function", Object::Callable(_) | Object::Class(_))
+        )
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pattern::Wildcard(token)
+            | Pattern::Literal(token, _)
+            | Pattern::Type(token)
+            | Pattern::Binding(token) => write!(f, "{}", token.get_lexeme()),
+            Pattern::List(_, items, rest) => {
+                let mut parts: Vec<_> = items.iter().map(ToString::to_string).collect();
+                match rest {
+                    Some(Rest(Some(name))) => parts.push(format!("..{}", name.get_lexeme())),
+                    Some(Rest(None)) => parts.push("..".into()),
+                    None => {}
+                }
+                write!(f, "[{}]", parts.join(", "))
+            }
+            Pattern::Dictionary(_, entries) => {
+                let parts: Vec<_> = entries
+                    .iter()
+                    .map(|(key, pattern)| match pattern {
+                        Pattern::Binding(name) if name.get_lexeme() == key.get_lexeme() => {
+                            key.get_lexeme().to_string()
+                        }
+                        _ => format!("{}: {}", key.get_lexeme(), pattern),
+                    })
+                    .collect();
+                write!(f, "{{{}}}", parts.join(", "))
+            }
+            Pattern::Variant(name, enum_name, items) => {
+                write!(f, "{}.{}", enum_name.get_lexeme(), name.get_lexeme())?;
+                if let Some(items) = items {
+                    let parts: Vec<_> = items.iter().map(ToString::to_string).collect();
+                    write!(f, "({})", parts.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::parser::Parser;
+    use crate::interpreter::scanner::Scanner;
+
+    fn pattern(code: &str) -> Pattern {
+        let tokens = Scanner::new(code).scan_tokens().unwrap();
+        Parser::<Result<Object>>::new(tokens).pattern().unwrap()
+    }
+
+    fn covers(first: &str, later: &str) -> bool {
+        pattern(first).covers(&pattern(later))
+    }
+
+    #[test]
+    fn wildcards_and_bindings_cover_everything() {
+        for later in ["1", "\"a\"", "[a, ..]", "{x}", "number", "Shape.Circle(r)"] {
+            assert!(covers("_", later), "_ covers {}", later);
+            assert!(covers("name", later), "name covers {}", later);
+        }
+    }
+
+    #[test]
+    fn literals_cover_only_the_same_value() {
+        assert!(covers("1", "1"));
+        assert!(covers("-2", "-2"));
+        assert!(covers("\"a\"", "\"a\""));
+        assert!(!covers("1", "2"));
+        assert!(!covers("1", "\"1\""));
+        assert!(!covers("1", "number"));
+        assert!(!covers("nil", "_"));
+    }
+
+    #[test]
+    fn types_cover_their_values() {
+        assert!(covers("number", "number"));
+        assert!(covers("number", "3"));
+        assert!(covers("string", "\"a\""));
+        assert!(covers("boolean", "true"));
+        assert!(covers("list", "[a, ..rest]"));
+        assert!(!covers("number", "\"a\""));
+        assert!(!covers("string", "number"));
+        assert!(!covers("dictionary", "[a]"));
+    }
+
+    #[test]
+    fn structural_patterns_are_not_assumed_to_cover() {
+        assert!(!covers("[a, b]", "[1, 2]"));
+        assert!(!covers("{x}", "{x: 1}"));
+    }
+
+    #[test]
+    fn variants_cover_by_name_and_payload() {
+        assert!(covers("Shape.Circle", "Shape.Circle(1)"));
+        assert!(covers("Shape.Circle(r)", "Shape.Circle"));
+        assert!(covers("Shape.Circle(_)", "Shape.Circle(1)"));
+        assert!(covers("Shape.Rect(w, 1)", "Shape.Rect(2, 1)"));
+        assert!(!covers("Shape.Circle(1)", "Shape.Circle"));
+        assert!(!covers("Shape.Circle(1)", "Shape.Circle(2)"));
+        assert!(!covers("Shape.Circle", "Shape.Square"));
+        assert!(!covers("Shape.Circle", "Form.Circle"));
+        assert!(!covers("Shape.Rect(w)", "Shape.Rect(1, 2)"));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+
+#[derive(Clone)]
+pub struct Break {
+    token: Token,
+}
+
+impl Break {
+    pub fn new(token: Token) -> Self {
+        Self { token }
+    }
+
+    pub fn get_token(&self) -> Token {
+        self.token.clone()
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for Break {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_break(self)
+    }
+}
@@ -11,6 +11,15 @@ pub struct Class<T: 'static> {
     #[allow(clippy::vec_box)]
     methods: Vec<Fun<T>>,
     super_class: Option<Variable>,
+    mixins: Vec<Variable>,
+    statics: Vec<Fun<T>>,
+    getters: Vec<Fun<T>>,
+    setters: Vec<Fun<T>>,
+    /// Instance field initialisers, lowered by the parser into a method
+    /// assigning each field on `self`.
+    fields: Option<Fun<T>>,
+    /// Static field initialisers, run once with `self` bound to the class.
+    static_fields: Option<Fun<T>>,
 }
 
 impl<T> Class<T> {
@@ -19,12 +28,64 @@ impl<T> Class<T> {
             name,
             methods,
             super_class,
+            mixins: vec![],
+            statics: vec![],
+            getters: vec![],
+            setters: vec![],
+            fields: None,
+            static_fields: None,
         }
     }
 
-    pub fn extract(&self) -> ExtractedClass<T> {
+    pub fn with_mixins(mut self, mixins: Vec<Variable>) -> Self {
+        self.mixins = mixins;
+        self
+    }
+
+    pub fn with_statics(mut self, statics: Vec<Fun<T>>, static_fields: Option<Fun<T>>) -> Self {
+        self.statics = statics;
+        self.static_fields = static_fields;
+        self
+    }
+
+    pub fn with_accessors(mut self, getters: Vec<Fun<T>>, setters: Vec<Fun<T>>) -> Self {
+        self.getters = getters;
+        self.setters = setters;
+        self
+    }
+
+    pub fn with_fields(mut self, fields: Option<Fun<T>>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    pub fn extract(&self) -> ExtractedClass<'_, T> {
         (&self.name, &self.methods, self.super_class.as_ref())
     }
+
+    pub fn get_mixins(&self) -> &Vec<Variable> {
+        &self.mixins
+    }
+
+    pub fn get_statics(&self) -> &Vec<Fun<T>> {
+        &self.statics
+    }
+
+    pub fn get_getters(&self) -> &Vec<Fun<T>> {
+        &self.getters
+    }
+
+    pub fn get_setters(&self) -> &Vec<Fun<T>> {
+        &self.setters
+    }
+
+    pub fn get_fields(&self) -> Option<&Fun<T>> {
+        self.fields.as_ref()
+    }
+
+    pub fn get_static_fields(&self) -> Option<&Fun<T>> {
+        self.static_fields.as_ref()
+    }
 }
 
 impl<T: 'static + Clone> Stmt<T> for Class<T> {
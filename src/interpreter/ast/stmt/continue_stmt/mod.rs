@@ -0,0 +1,23 @@
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+
+#[derive(Clone)]
+pub struct Continue {
+    token: Token,
+}
+
+impl Continue {
+    pub fn new(token: Token) -> Self {
+        Self { token }
+    }
+
+    pub fn get_token(&self) -> Token {
+        self.token.clone()
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for Continue {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_continue(self)
+    }
+}
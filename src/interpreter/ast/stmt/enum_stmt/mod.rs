@@ -0,0 +1,25 @@
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+
+/// `enum Name { Unit, WithPayload(a, b) }`
+#[derive(Clone)]
+pub struct Enum {
+    name: Token,
+    variants: Vec<(Token, Vec<Token>)>,
+}
+
+impl Enum {
+    pub fn new(name: Token, variants: Vec<(Token, Vec<Token>)>) -> Self {
+        Self { name, variants }
+    }
+
+    pub fn extract(&self) -> (&Token, &Vec<(Token, Vec<Token>)>) {
+        (&self.name, &self.variants)
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for Enum {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_enum(self)
+    }
+}
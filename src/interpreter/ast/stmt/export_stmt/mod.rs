@@ -1,20 +1,40 @@
+use crate::interpreter::ast::expr::variable::Variable;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
 use crate::interpreter::scanner::token::Token;
-use std::ops::Deref;
 
+/// `export let a = ..;` or `export { a, b as c };`.
 #[derive(Clone)]
 pub struct Export<T: 'static> {
     name: Token,
-    stmt: Box<dyn Stmt<T>>,
+    stmt: Option<Box<dyn Stmt<T>>>,
+    /// Existing bindings exported by an export list, each with the name it
+    /// is exported as.
+    names: Vec<(Variable, Token)>,
 }
 
 impl<T> Export<T> {
     pub fn new(name: Token, stmt: Box<dyn Stmt<T>>) -> Self {
-        Self { name, stmt }
+        Self {
+            name,
+            stmt: Some(stmt),
+            names: vec![],
+        }
     }
 
-    pub fn extract(&self) -> (&Token, &dyn Stmt<T>) {
-        (&self.name, self.stmt.deref())
+    pub fn list(name: Token, names: Vec<(Variable, Token)>) -> Self {
+        Self {
+            name,
+            stmt: None,
+            names,
+        }
+    }
+
+    pub fn extract(&self) -> (&Token, Option<&dyn Stmt<T>>) {
+        (&self.name, self.stmt.as_deref())
+    }
+
+    pub fn get_names(&self) -> &[(Variable, Token)] {
+        &self.names
     }
 }
 
@@ -0,0 +1,47 @@
+use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+use std::ops::Deref;
+
+type ExtractedForIn<'a, T> = (&'a [Token], &'a Token, &'a dyn Expr<T>, &'a dyn Stmt<T>);
+
+/// `for (x in iterable)` or `for (key, value in iterable)`. Every iteration
+/// binds the loop variables in a fresh environment.
+#[derive(Clone)]
+pub struct ForIn<T: 'static> {
+    names: Vec<Token>,
+    keyword: Token,
+    iterable: Box<dyn Expr<T>>,
+    body: Box<dyn Stmt<T>>,
+}
+
+impl<T> ForIn<T> {
+    pub fn new(
+        names: Vec<Token>,
+        keyword: Token,
+        iterable: Box<dyn Expr<T>>,
+        body: Box<dyn Stmt<T>>,
+    ) -> Self {
+        Self {
+            names,
+            keyword,
+            iterable,
+            body,
+        }
+    }
+
+    pub fn extract(&self) -> ExtractedForIn<'_, T> {
+        (
+            &self.names,
+            &self.keyword,
+            self.iterable.deref(),
+            self.body.deref(),
+        )
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for ForIn<T> {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_for_in(self)
+    }
+}
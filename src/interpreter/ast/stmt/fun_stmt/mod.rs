@@ -1,19 +1,20 @@
+use crate::interpreter::ast::param::Param;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
 use crate::interpreter::scanner::token::Token;
 use crate::utils::next_id;
 
-type Extract<T> = (u64, Token, Vec<Token>, Vec<Box<dyn Stmt<T>>>);
+type Extract<T> = (u64, Token, Vec<Param<T>>, Vec<Box<dyn Stmt<T>>>);
 
 #[derive(Clone)]
 pub struct Fun<T: 'static> {
     id: u64,
     name: Token,
-    params: Vec<Token>,
+    params: Vec<Param<T>>,
     body: Vec<Box<dyn Stmt<T>>>,
 }
 
 impl<T> Fun<T> {
-    pub fn new(name: Token, params: Vec<Token>, body: Vec<Box<dyn Stmt<T>>>) -> Self {
+    pub fn new(name: Token, params: Vec<Param<T>>, body: Vec<Box<dyn Stmt<T>>>) -> Self {
         Self {
             id: next_id(),
             name,
@@ -29,6 +30,10 @@ impl<T> Fun<T> {
     pub fn get_name(&self) -> Token {
         self.name.clone()
     }
+
+    pub fn get_params(&self) -> &[Param<T>] {
+        &self.params
+    }
 }
 
 impl<T: 'static + Clone> Stmt<T> for Fun<T> {
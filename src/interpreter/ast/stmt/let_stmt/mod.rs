@@ -1,4 +1,5 @@
 use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::ast::pattern::Pattern;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
 use crate::interpreter::scanner::token::Token;
 use std::ops::Deref;
@@ -6,18 +7,49 @@ use std::ops::Deref;
 #[derive(Clone)]
 pub struct Let<T: 'static> {
     ident: Token,
+    pattern: Option<Pattern>,
     initializer: Option<Box<dyn Expr<T>>>,
+    is_const: bool,
 }
 
 impl<T> Let<T> {
     pub fn new(ident: Token, initializer: Option<Box<dyn Expr<T>>>) -> Self {
-        Self { ident, initializer }
+        Self {
+            ident,
+            pattern: None,
+            initializer,
+            is_const: false,
+        }
+    }
+
+    /// Destructuring `let [a, b] = value;` / `let {x, y} = value;`.
+    pub fn with_pattern(pattern: Pattern, initializer: Box<dyn Expr<T>>) -> Self {
+        Self {
+            ident: pattern.get_token().clone(),
+            pattern: Some(pattern),
+            initializer: Some(initializer),
+            is_const: false,
+        }
+    }
+
+    /// `const` instead of `let`: the names can't be assigned again.
+    pub fn constant(mut self) -> Self {
+        self.is_const = true;
+        self
+    }
+
+    pub fn is_const(&self) -> bool {
+        self.is_const
     }
 
     pub fn get_ident(&self) -> Token {
         self.ident.clone()
     }
 
+    pub fn get_pattern(&self) -> Option<&Pattern> {
+        self.pattern.as_ref()
+    }
+
     pub fn get_initializer(&self) -> Option<&dyn Expr<T>> {
         if let Some(initializer) = &self.initializer {
             return Some(initializer.deref());
@@ -1,25 +1,39 @@
 use crate::interpreter::ast::stmt::block::Block;
+use crate::interpreter::ast::stmt::break_stmt::Break;
 use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::continue_stmt::Continue;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
+use crate::interpreter::ast::stmt::for_in_stmt::ForIn;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::ast::stmt::if_stmt::If;
 use crate::interpreter::ast::stmt::let_stmt::Let;
 use crate::interpreter::ast::stmt::print::Print;
 use crate::interpreter::ast::stmt::return_stmt::Return;
 use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
+use crate::interpreter::ast::stmt::throw_stmt::Throw;
+use crate::interpreter::ast::stmt::trait_stmt::Trait;
+use crate::interpreter::ast::stmt::try_stmt::Try;
 use crate::interpreter::ast::stmt::use_stmt::Use;
 use crate::interpreter::ast::stmt::while_stmt::While;
 use downcast_rs::{Downcast, impl_downcast};
 
 pub mod block;
+pub mod break_stmt;
 pub mod class;
+pub mod continue_stmt;
+pub mod enum_stmt;
 pub mod export_stmt;
+pub mod for_in_stmt;
 pub mod fun_stmt;
 pub mod if_stmt;
 pub mod let_stmt;
 pub mod print;
 pub mod return_stmt;
 pub mod stmt_expr;
+pub mod throw_stmt;
+pub mod trait_stmt;
+pub mod try_stmt;
 pub mod use_stmt;
 pub mod while_stmt;
 
@@ -30,11 +44,18 @@ pub trait StmtVisitor<T> {
     fn visit_block(&mut self, stmt: &Block<T>) -> T;
     fn visit_if(&mut self, stmt: &If<T>) -> T;
     fn visit_while(&mut self, stmt: &While<T>) -> T;
+    fn visit_for_in(&mut self, stmt: &ForIn<T>) -> T;
     fn visit_fun(&mut self, stmt: &Fun<T>) -> T;
     fn visit_return(&mut self, stmt: &Return<T>) -> T;
     fn visit_class(&mut self, stmt: &Class<T>) -> T;
+    fn visit_trait(&mut self, stmt: &Trait<T>) -> T;
+    fn visit_enum(&mut self, stmt: &Enum) -> T;
     fn visit_export(&mut self, stmt: &Export<T>) -> T;
     fn visit_use(&mut self, stmt: &Use<T>) -> T;
+    fn visit_break(&mut self, stmt: &Break) -> T;
+    fn visit_continue(&mut self, stmt: &Continue) -> T;
+    fn visit_try(&mut self, stmt: &Try<T>) -> T;
+    fn visit_throw(&mut self, stmt: &Throw<T>) -> T;
 }
 
 pub trait CloneStmt<T> {
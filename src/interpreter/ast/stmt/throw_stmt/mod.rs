@@ -0,0 +1,26 @@
+use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+use std::ops::Deref;
+
+#[derive(Clone)]
+pub struct Throw<T: 'static> {
+    keyword: Token,
+    value: Box<dyn Expr<T>>,
+}
+
+impl<T> Throw<T> {
+    pub fn new(keyword: Token, value: Box<dyn Expr<T>>) -> Self {
+        Self { keyword, value }
+    }
+
+    pub fn extract(&self) -> (&Token, &dyn Expr<T>) {
+        (&self.keyword, self.value.deref())
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for Throw<T> {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_throw(self)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::interpreter::ast::stmt::fun_stmt::Fun;
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+
+type ExtractedTrait<'a, T> = (&'a Token, &'a Vec<Fun<T>>, &'a Vec<Token>);
+
+/// `trait Name { provided() { .. } required(); }`, a set of methods that
+/// classes include with `class Name with Trait { .. }`.
+#[derive(Clone)]
+pub struct Trait<T: 'static> {
+    name: Token,
+    methods: Vec<Fun<T>>,
+    /// Methods declared without a body, which every including class must
+    /// define itself or inherit.
+    required: Vec<Token>,
+}
+
+impl<T> Trait<T> {
+    pub fn new(name: Token, methods: Vec<Fun<T>>, required: Vec<Token>) -> Self {
+        Self {
+            name,
+            methods,
+            required,
+        }
+    }
+
+    pub fn extract(&self) -> ExtractedTrait<'_, T> {
+        (&self.name, &self.methods, &self.required)
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for Trait<T> {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_trait(self)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+
+type ExtractedTry<'a, T> = (
+    Vec<&'a dyn Stmt<T>>,
+    Option<(Option<&'a Token>, Vec<&'a dyn Stmt<T>>)>,
+    Option<Vec<&'a dyn Stmt<T>>>,
+);
+
+/// `catch (name) { .. }`; the name is optional.
+#[derive(Clone)]
+pub struct Catch<T: 'static> {
+    name: Option<Token>,
+    body: Vec<Box<dyn Stmt<T>>>,
+}
+
+impl<T> Catch<T> {
+    pub fn new(name: Option<Token>, body: Vec<Box<dyn Stmt<T>>>) -> Self {
+        Self { name, body }
+    }
+}
+
+#[derive(Clone)]
+pub struct Try<T: 'static> {
+    keyword: Token,
+    body: Vec<Box<dyn Stmt<T>>>,
+    catch: Option<Catch<T>>,
+    finally: Option<Vec<Box<dyn Stmt<T>>>>,
+}
+
+impl<T> Try<T> {
+    pub fn new(
+        keyword: Token,
+        body: Vec<Box<dyn Stmt<T>>>,
+        catch: Option<Catch<T>>,
+        finally: Option<Vec<Box<dyn Stmt<T>>>>,
+    ) -> Self {
+        Self {
+            keyword,
+            body,
+            catch,
+            finally,
+        }
+    }
+
+    pub fn get_token(&self) -> &Token {
+        &self.keyword
+    }
+
+    pub fn extract(&self) -> ExtractedTry<'_, T> {
+        (
+            self.body.iter().map(AsRef::as_ref).collect(),
+            self.catch.as_ref().map(|catch| {
+                (
+                    catch.name.as_ref(),
+                    catch.body.iter().map(AsRef::as_ref).collect(),
+                )
+            }),
+            self.finally
+                .as_ref()
+                .map(|finally| finally.iter().map(AsRef::as_ref).collect()),
+        )
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for Try<T> {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_try(self)
+    }
+}
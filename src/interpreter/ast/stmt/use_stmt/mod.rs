@@ -2,21 +2,72 @@ use crate::interpreter::ast::expr::Expr;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
 use crate::interpreter::scanner::token::Token;
 use std::ops::Deref;
+use std::path::PathBuf;
+
+/// What a `use` binds in the importing file.
+#[derive(Debug, Clone)]
+pub enum Import {
+    /// `use "x";`, the exported declarations are spliced into the importer.
+    All,
+    /// `use "x" as m;`, the exports become fields of a module value `m`.
+    Module(Token),
+    /// `use {a, b} from "x";`, only the listed exports are bound.
+    Names(Vec<Token>),
+}
 
 #[derive(Clone)]
 pub struct Use<T: 'static> {
     name: Token,
     expr: Box<dyn Expr<T>>,
+    import: Import,
+    /// Canonical path of the imported file, set by the `Exporter`.
+    path: Option<PathBuf>,
+    /// Statements of the imported file, attached by the `Exporter` for
+    /// namespaced and selective imports.
+    module: Option<Vec<Box<dyn Stmt<T>>>>,
 }
 
 impl<T> Use<T> {
     pub fn new(name: Token, expr: Box<dyn Expr<T>>) -> Self {
-        Self { name, expr }
+        Self {
+            name,
+            expr,
+            import: Import::All,
+            path: None,
+            module: None,
+        }
+    }
+
+    pub fn with_import(mut self, import: Import) -> Self {
+        self.import = import;
+        self
+    }
+
+    pub fn with_path(mut self, path: PathBuf) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    pub fn with_module(mut self, module: Vec<Box<dyn Stmt<T>>>) -> Self {
+        self.module = Some(module);
+        self
     }
 
     pub fn extract(&self) -> (&Token, &dyn Expr<T>) {
         (&self.name, self.expr.deref())
     }
+
+    pub fn get_import(&self) -> &Import {
+        &self.import
+    }
+
+    pub fn get_path(&self) -> Option<&PathBuf> {
+        self.path.as_ref()
+    }
+
+    pub fn get_module(&self) -> Option<&Vec<Box<dyn Stmt<T>>>> {
+        self.module.as_ref()
+    }
 }
 
 impl<T: 'static + Clone> Stmt<T> for Use<T> {
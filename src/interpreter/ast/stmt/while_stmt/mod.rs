@@ -2,19 +2,44 @@ use crate::interpreter::ast::expr::Expr;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
 use std::ops::Deref;
 
+type ExtractedWhile<'a, T> = (&'a dyn Expr<T>, &'a dyn Stmt<T>, Option<&'a dyn Expr<T>>);
+
 #[derive(Clone)]
 pub struct While<T: 'static> {
     cond: Box<dyn Expr<T>>,
     stmt: Box<dyn Stmt<T>>,
+    increment: Option<Box<dyn Expr<T>>>,
 }
 
 impl<T> While<T> {
     pub fn new(cond: Box<dyn Expr<T>>, stmt: Box<dyn Stmt<T>>) -> Self {
-        Self { cond, stmt }
+        Self {
+            cond,
+            stmt,
+            increment: None,
+        }
+    }
+
+    /// Loop produced by `for` desugaring: `increment` runs after every
+    /// iteration, including ones cut short by `continue`.
+    pub fn with_increment(
+        cond: Box<dyn Expr<T>>,
+        stmt: Box<dyn Stmt<T>>,
+        increment: Option<Box<dyn Expr<T>>>,
+    ) -> Self {
+        Self {
+            cond,
+            stmt,
+            increment,
+        }
     }
 
-    pub fn extract(&self) -> (&dyn Expr<T>, &dyn Stmt<T>) {
-        (self.cond.deref(), self.stmt.deref())
+    pub fn extract(&self) -> ExtractedWhile<'_, T> {
+        (
+            self.cond.deref(),
+            self.stmt.deref(),
+            self.increment.as_deref(),
+        )
     }
 }
 
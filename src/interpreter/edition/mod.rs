@@ -0,0 +1,44 @@
+use serde::{Deserialize, Deserializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Language edition a script is run with. Newer editions change behaviour
+/// that older scripts rely on, so they are opt-in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Edition {
+    /// `list + value` pushes `value` into `list` in place.
+    #[default]
+    E2025,
+    /// `list + list` gives a new concatenated list, leaving both untouched;
+    /// adding anything else to a list is an error.
+    E2026,
+}
+
+impl FromStr for Edition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2025" => Ok(Edition::E2025),
+            "2026" => Ok(Edition::E2026),
+            _ => Err(format!("unknown edition '{}', expected 2025 or 2026", s)),
+        }
+    }
+}
+
+impl Display for Edition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Edition::E2025 => write!(f, "2025"),
+            Edition::E2026 => write!(f, "2026"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Edition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
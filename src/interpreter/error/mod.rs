@@ -2,13 +2,18 @@ use crate::interpreter::Interpreter;
 use crate::interpreter::event::InterpreterEvent;
 use crate::interpreter::exporter::error::ExporterError;
 use crate::interpreter::object::Object;
+use crate::interpreter::object::callable::Arity;
+use crate::interpreter::object::freeze::Freezable;
 use crate::interpreter::parser::error::ParserError;
 use crate::interpreter::scanner::error::ScannerError;
 use crate::interpreter::scanner::token::Token;
+use crate::rc;
 use glium::winit::error::EventLoopError;
 use glium::winit::event_loop::EventLoopClosed;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::num::ParseFloatError;
+use std::sync::Arc;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, InterpreterError>;
@@ -29,6 +34,23 @@ pub enum InterpreterError {
     Custom(String),
     #[error("{0}")]
     Return(Object),
+    #[error("Unexpected 'break'")]
+    Break,
+    #[error("Unexpected 'continue'")]
+    Continue,
+}
+
+impl InterpreterError {
+    /// The value a `catch` clause binds for this error: a thrown value as is,
+    /// a runtime error as a `{kind, message, line}` dictionary. Panics,
+    /// parse errors and control flow can't be caught.
+    pub fn to_catchable(&self) -> Option<Object> {
+        match self {
+            InterpreterError::RuntimeError(err) => Some(err.ty.to_object(Some(&err.token))),
+            InterpreterError::RuntimeErrorType(ty) => Some(ty.to_object(None)),
+            _ => None,
+        }
+    }
 }
 
 impl From<EventLoopError> for InterpreterError {
@@ -90,20 +112,121 @@ pub enum RuntimeErrorType {
     CannotMultiplyTypes(String, String),
     CannotDivideTypes(String, String),
     CannotNegateType(String),
+    CannotModuloTypes(String, String),
+    CannotExponentiateTypes(String, String),
+    CannotApplyBitwiseOperator(String, String, String),
+    CannotBitwiseNotType(String),
+    ExpectedIntegerOperand(String),
+    InvalidShiftCount(i64),
+    IntegerOutOfRange(String),
     UnsupportedUnaryOperator(String),
     UnsupportedBinaryOperator(String),
     BugEnvironmentNotInit,
     UndefinedVariable(String),
     VariableIsNotInit(String),
-    ArityOfFuncNotEqSizeOfArgs,
+    ArityMismatch(Arity, usize),
+    UnknownParameter(String),
+    ArgumentGivenTwice(String),
+    MissingArgument(String),
     NotCallable,
     UserPanicWithMsg(Object),
     CantToNum(String),
     OnlyInstancesHaveProperties,
     UndefinedProperty(String),
+    ReadOnlyProperty(String),
     SuperclassMustBeClass,
+    MixinMustBeTrait,
+    UndefinedVariant(String, String),
+    MissingTraitMethod(String, String),
     MustBeANumber,
-    OnlyListsHaveIndices,
+    CannotCompareTypes(String, String),
+    NotIndexable(String),
+    InvalidIndex(String),
+    IndexOutOfBounds(usize, usize),
+    InvalidKey(String),
+    CannotMakeRange(String, String),
+    NotIterable(String),
+    ExpectedPair(String),
+    NoMatchingArm(String),
+    PatternMismatch(String, String),
+    Thrown(Object),
+    InvalidComponents(String, usize, usize),
+    InvalidArgument(String, String),
+    NotInvertible(String),
+    CannotAssignComponent(String),
+    FrozenValue(String),
+    UnknownPrimitive(String),
+    InvalidUniform(String, String),
+}
+
+impl RuntimeErrorType {
+    /// Name of the variant, e.g. `CannotAddTypes`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            RuntimeErrorType::CannotAddTypes(..) => "CannotAddTypes",
+            RuntimeErrorType::CannotSubtractTypes(..) => "CannotSubtractTypes",
+            RuntimeErrorType::CannotMultiplyTypes(..) => "CannotMultiplyTypes",
+            RuntimeErrorType::CannotDivideTypes(..) => "CannotDivideTypes",
+            RuntimeErrorType::CannotNegateType(..) => "CannotNegateType",
+            RuntimeErrorType::CannotModuloTypes(..) => "CannotModuloTypes",
+            RuntimeErrorType::CannotExponentiateTypes(..) => "CannotExponentiateTypes",
+            RuntimeErrorType::CannotApplyBitwiseOperator(..) => "CannotApplyBitwiseOperator",
+            RuntimeErrorType::CannotBitwiseNotType(..) => "CannotBitwiseNotType",
+            RuntimeErrorType::ExpectedIntegerOperand(..) => "ExpectedIntegerOperand",
+            RuntimeErrorType::InvalidShiftCount(..) => "InvalidShiftCount",
+            RuntimeErrorType::IntegerOutOfRange(..) => "IntegerOutOfRange",
+            RuntimeErrorType::UnsupportedUnaryOperator(..) => "UnsupportedUnaryOperator",
+            RuntimeErrorType::UnsupportedBinaryOperator(..) => "UnsupportedBinaryOperator",
+            RuntimeErrorType::BugEnvironmentNotInit => "BugEnvironmentNotInit",
+            RuntimeErrorType::UndefinedVariable(..) => "UndefinedVariable",
+            RuntimeErrorType::VariableIsNotInit(..) => "VariableIsNotInit",
+            RuntimeErrorType::ArityMismatch(..) => "ArityMismatch",
+            RuntimeErrorType::UnknownParameter(..) => "UnknownParameter",
+            RuntimeErrorType::ArgumentGivenTwice(..) => "ArgumentGivenTwice",
+            RuntimeErrorType::MissingArgument(..) => "MissingArgument",
+            RuntimeErrorType::NotCallable => "NotCallable",
+            RuntimeErrorType::UserPanicWithMsg(..) => "UserPanicWithMsg",
+            RuntimeErrorType::CantToNum(..) => "CantToNum",
+            RuntimeErrorType::OnlyInstancesHaveProperties => "OnlyInstancesHaveProperties",
+            RuntimeErrorType::UndefinedProperty(..) => "UndefinedProperty",
+            RuntimeErrorType::ReadOnlyProperty(..) => "ReadOnlyProperty",
+            RuntimeErrorType::SuperclassMustBeClass => "SuperclassMustBeClass",
+            RuntimeErrorType::MixinMustBeTrait => "MixinMustBeTrait",
+            RuntimeErrorType::UndefinedVariant(..) => "UndefinedVariant",
+            RuntimeErrorType::MissingTraitMethod(..) => "MissingTraitMethod",
+            RuntimeErrorType::MustBeANumber => "MustBeANumber",
+            RuntimeErrorType::CannotCompareTypes(..) => "CannotCompareTypes",
+            RuntimeErrorType::NotIndexable(..) => "NotIndexable",
+            RuntimeErrorType::InvalidIndex(..) => "InvalidIndex",
+            RuntimeErrorType::IndexOutOfBounds(..) => "IndexOutOfBounds",
+            RuntimeErrorType::InvalidKey(..) => "InvalidKey",
+            RuntimeErrorType::CannotMakeRange(..) => "CannotMakeRange",
+            RuntimeErrorType::NotIterable(..) => "NotIterable",
+            RuntimeErrorType::ExpectedPair(..) => "ExpectedPair",
+            RuntimeErrorType::NoMatchingArm(..) => "NoMatchingArm",
+            RuntimeErrorType::PatternMismatch(..) => "PatternMismatch",
+            RuntimeErrorType::Thrown(..) => "Thrown",
+            RuntimeErrorType::InvalidComponents(..) => "InvalidComponents",
+            RuntimeErrorType::InvalidArgument(..) => "InvalidArgument",
+            RuntimeErrorType::NotInvertible(..) => "NotInvertible",
+            RuntimeErrorType::CannotAssignComponent(..) => "CannotAssignComponent",
+            RuntimeErrorType::FrozenValue(..) => "FrozenValue",
+            RuntimeErrorType::UnknownPrimitive(..) => "UnknownPrimitive",
+            RuntimeErrorType::InvalidUniform(..) => "InvalidUniform",
+        }
+    }
+
+    fn to_object(&self, token: Option<&Token>) -> Object {
+        if let RuntimeErrorType::Thrown(value) = self {
+            return value.clone();
+        }
+        let line = token.map_or(Object::Nil, |token| Object::Number(token.get_line() as f64));
+        Object::Dictionary(rc!(Freezable::new(HashMap::from([
+            ("kind".to_string(), Object::String(self.kind().to_string())),
+            ("message".to_string(), Object::String(self.to_string())),
+            ("line".to_string(), line),
+        ]))))
+    }
 }
 
 impl Display for RuntimeErrorType {
@@ -122,6 +245,27 @@ impl Display for RuntimeErrorType {
                 write!(f, "Cannot divide '{}' and '{}'", ty1, ty2)
             }
             RuntimeErrorType::CannotNegateType(ty) => write!(f, "Cannot negate '{}'", ty),
+            RuntimeErrorType::CannotModuloTypes(ty1, ty2) => {
+                write!(f, "Cannot take remainder of '{}' and '{}'", ty1, ty2)
+            }
+            RuntimeErrorType::CannotExponentiateTypes(ty1, ty2) => {
+                write!(f, "Cannot raise '{}' to the power of '{}'", ty1, ty2)
+            }
+            RuntimeErrorType::CannotApplyBitwiseOperator(op, ty1, ty2) => {
+                write!(f, "Cannot apply '{}' to '{}' and '{}'", op, ty1, ty2)
+            }
+            RuntimeErrorType::CannotBitwiseNotType(ty) => {
+                write!(f, "Cannot apply '~' to '{}'", ty)
+            }
+            RuntimeErrorType::ExpectedIntegerOperand(value) => {
+                write!(f, "Bitwise operand '{}' is not an integer", value)
+            }
+            RuntimeErrorType::InvalidShiftCount(count) => {
+                write!(f, "Shift count {} is outside 0..64", count)
+            }
+            RuntimeErrorType::IntegerOutOfRange(value) => {
+                write!(f, "Integer '{}' is outside -2^53..2^53", value)
+            }
             RuntimeErrorType::UnsupportedUnaryOperator(op) => {
                 write!(f, "Unsupported unary operator '{}'", op)
             }
@@ -133,8 +277,39 @@ impl Display for RuntimeErrorType {
             RuntimeErrorType::VariableIsNotInit(v) => {
                 write!(f, "Variable '{}' is not initialized", v)
             }
-            RuntimeErrorType::ArityOfFuncNotEqSizeOfArgs => {
-                write!(f, "Arity of functions not equal size of args")
+            RuntimeErrorType::Thrown(value) => write!(f, "Uncaught {}", value),
+            RuntimeErrorType::InvalidComponents(ty, expected, got) => {
+                write!(f, "'{}' takes {} components but got {}", ty, expected, got)
+            }
+            RuntimeErrorType::InvalidArgument(expected, got) => {
+                write!(f, "Expected {} but got '{}'", expected, got)
+            }
+            RuntimeErrorType::NotInvertible(value) => write!(f, "'{}' is not invertible", value),
+            RuntimeErrorType::FrozenValue(ty) => {
+                write!(f, "Cannot modify a frozen {}", ty)
+            }
+            RuntimeErrorType::UnknownPrimitive(name) => {
+                write!(f, "Unknown primitive '{}'", name)
+            }
+            RuntimeErrorType::InvalidUniform(name, reason) => {
+                write!(f, "Uniform '{}' {}", name, reason)
+            }
+            RuntimeErrorType::CannotAssignComponent(ty) => write!(
+                f,
+                "Cannot assign to a component of '{}', build a new value instead",
+                ty
+            ),
+            RuntimeErrorType::ArityMismatch(arity, got) => {
+                write!(f, "Expected {} argument(s) but got {}", arity, got)
+            }
+            RuntimeErrorType::UnknownParameter(name) => {
+                write!(f, "No parameter named '{}'", name)
+            }
+            RuntimeErrorType::ArgumentGivenTwice(name) => {
+                write!(f, "Argument '{}' given more than once", name)
+            }
+            RuntimeErrorType::MissingArgument(name) => {
+                write!(f, "Missing argument '{}'", name)
             }
             RuntimeErrorType::NotCallable => write!(f, "Not callable"),
             RuntimeErrorType::UserPanicWithMsg(msg) => write!(f, "{}", msg),
@@ -145,9 +320,54 @@ impl Display for RuntimeErrorType {
                 write!(f, "Only instances have properties")
             }
             RuntimeErrorType::UndefinedProperty(name) => write!(f, "Undefined property '{}'", name),
+            RuntimeErrorType::ReadOnlyProperty(name) => {
+                write!(f, "Property '{}' has a getter but no setter", name)
+            }
+            RuntimeErrorType::UndefinedVariant(name, variant) => {
+                write!(f, "Enum '{}' has no variant '{}'", name, variant)
+            }
+            RuntimeErrorType::MixinMustBeTrait => write!(f, "Only traits can be included"),
+            RuntimeErrorType::MissingTraitMethod(name, method) => write!(
+                f,
+                "Class doesn't define method '{}' required by trait '{}'",
+                method, name
+            ),
             RuntimeErrorType::SuperclassMustBeClass => write!(f, "Superclass must be class"),
             RuntimeErrorType::MustBeANumber => write!(f, "Must be a number"),
-            RuntimeErrorType::OnlyListsHaveIndices => write!(f, "Only lists have indices"),
+            RuntimeErrorType::CannotCompareTypes(ty1, ty2) => {
+                write!(f, "Cannot compare '{}' and '{}'", ty1, ty2)
+            }
+            RuntimeErrorType::NotIndexable(ty) => write!(f, "Type '{}' cannot be indexed", ty),
+            RuntimeErrorType::InvalidIndex(index) => {
+                write!(f, "List index '{}' is not a non-negative integer", index)
+            }
+            RuntimeErrorType::IndexOutOfBounds(index, len) => {
+                write!(
+                    f,
+                    "Index {} is out of bounds for list of length {}",
+                    index, len
+                )
+            }
+            RuntimeErrorType::InvalidKey(ty) => {
+                write!(f, "Type '{}' cannot be used as a key", ty)
+            }
+            RuntimeErrorType::CannotMakeRange(ty1, ty2) => {
+                write!(f, "Cannot make a range from '{}' to '{}'", ty1, ty2)
+            }
+            RuntimeErrorType::NotIterable(ty) => write!(f, "Type '{}' is not iterable", ty),
+            RuntimeErrorType::PatternMismatch(value, pattern) => {
+                write!(
+                    f,
+                    "Cannot destructure '{}' with pattern '{}'",
+                    value, pattern
+                )
+            }
+            RuntimeErrorType::NoMatchingArm(value) => {
+                write!(f, "No match arm matches '{}'", value)
+            }
+            RuntimeErrorType::ExpectedPair(value) => {
+                write!(f, "Expected a [key, value] pair, got '{}'", value)
+            }
         }
     }
 }
@@ -13,6 +13,14 @@ impl ExporterError {
     pub fn new(token: Token, ty: ExporterErrorType) -> Self {
         Self { token, ty }
     }
+
+    pub fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    pub fn get_type(&self) -> &ExporterErrorType {
+        &self.ty
+    }
 }
 
 impl Display for ExporterError {
@@ -31,9 +39,12 @@ impl From<ExporterError> for InterpreterError {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ExporterErrorType {
     ExpectedPathStringAfterUse,
+    ModuleNotFound(String),
+    CantReadModule(String, String),
+    ImportCycle(String),
 }
 
 impl Display for ExporterErrorType {
@@ -42,6 +53,13 @@ impl Display for ExporterErrorType {
             ExporterErrorType::ExpectedPathStringAfterUse => {
                 write!(f, "Expected path string after use")
             }
+            ExporterErrorType::ModuleNotFound(path) => {
+                write!(f, "Cannot find module '{}'", path)
+            }
+            ExporterErrorType::CantReadModule(path, reason) => {
+                write!(f, "Cannot read module '{}': {}", path, reason)
+            }
+            ExporterErrorType::ImportCycle(chain) => write!(f, "Import cycle: {}", chain),
         }
     }
 }
@@ -1,66 +1,436 @@
 use crate::b;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::stmt::Stmt;
+use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
-use crate::interpreter::ast::stmt::use_stmt::Use;
+use crate::interpreter::ast::stmt::fun_stmt::Fun;
+use crate::interpreter::ast::stmt::let_stmt::Let;
+use crate::interpreter::ast::stmt::trait_stmt::Trait;
+use crate::interpreter::ast::stmt::use_stmt::{Import, Use};
 use crate::interpreter::error::Result;
 use crate::interpreter::exporter::error::{ExporterError, ExporterErrorType};
 use crate::interpreter::object::Object;
 use crate::interpreter::parser::Parser;
 use crate::interpreter::scanner::Scanner;
+use crate::interpreter::scanner::token::Token;
+use crate::interpreter::scanner::token::token_type::TokenType;
+use std::collections::{HashMap, HashSet};
 use std::fs::read_to_string;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub mod error;
 
+/// Modules of the standard library, bundled into the binary and imported
+/// as `use "std/<name>";`.
+const STD: [(&str, &str); 1] = [("std/math.yun", include_str!("../../../std/math.yun"))];
+
+/// Source of a bundled standard library module.
+fn bundled(path: &Path) -> Option<&'static str> {
+    STD.iter()
+        .find(|(name, _)| Path::new(name) == path)
+        .map(|(_, code)| *code)
+}
+
 pub struct Exporter<T> {
     ast: Vec<Box<dyn Stmt<T>>>,
     path: PathBuf,
+    /// Every file loaded so far by canonical path, each parsed once, with
+    /// its `use`s pointing at the canonical paths of their modules.
+    modules: HashMap<PathBuf, Vec<Box<dyn Stmt<T>>>>,
+    /// Files whose imports are being loaded, outermost first.
+    loading: Vec<PathBuf>,
+    /// Directories searched for modules not found next to the importer.
+    search_paths: Vec<PathBuf>,
 }
 
 impl<T: 'static + Clone> Exporter<T> {
     pub fn new(path: PathBuf, ast: Vec<Box<dyn Stmt<T>>>) -> Self {
-        Self { path, ast }
-    }
-
-    pub fn resolve(self) -> Result<Vec<Box<dyn Stmt<T>>>> {
-        let mut ast = vec![];
-        for stmt in self.ast.clone() {
-            if let Some(use_stmt) = stmt.downcast_ref::<Use<T>>() {
-                let (name, expr) = use_stmt.extract();
-                if let Some(expr) = expr.downcast_ref::<Literal>() {
-                    if let Some(Object::String(path)) = expr.get_value() {
-                        let path = self.path.parent().unwrap().join(path.to_owned() + ".yun");
-                        let code = read_to_string(path.clone()).unwrap();
-                        let tokens = Scanner::new(&code).scan_tokens()?;
-                        let _ast = Parser::new(tokens).parse()?;
-                        let _ast = Exporter::new(path.clone(), _ast).resolve()?;
-                        let exported_ast = self.sift(_ast)?;
-                        for stmt in exported_ast {
-                            ast.push(stmt);
-                        }
-                    } else {
-                        return Err(ExporterError::new(
-                            name.clone(),
-                            ExporterErrorType::ExpectedPathStringAfterUse,
-                        )
-                        .into());
-                    }
+        Self {
+            path,
+            ast,
+            modules: HashMap::new(),
+            loading: vec![],
+            search_paths: vec![],
+        }
+    }
+
+    pub fn with_search_paths(mut self, search_paths: Vec<PathBuf>) -> Self {
+        self.search_paths = search_paths;
+        self
+    }
+
+    /// Replaces every `use` with what it imports: the exported statements
+    /// of the file for a plain `use`, or the `use` itself carrying the whole
+    /// file for namespaced and selective imports and for plain imports of
+    /// files with an export list.
+    pub fn resolve(mut self) -> Result<Vec<Box<dyn Stmt<T>>>> {
+        let root = self
+            .path
+            .canonicalize()
+            .unwrap_or_else(|_| self.path.clone());
+        let ast = std::mem::take(&mut self.ast);
+        self.loading.push(root.clone());
+        let ast = self.link(ast, &root)?;
+        self.loading.pop();
+        self.flatten(ast, &mut HashSet::from([root]))
+    }
+
+    /// Loads the module of every `use` in `ast`, the statements of the file
+    /// at `path`, and records its canonical path on the `use`.
+    fn link(&mut self, ast: Vec<Box<dyn Stmt<T>>>, path: &Path) -> Result<Vec<Box<dyn Stmt<T>>>> {
+        let mut linked: Vec<Box<dyn Stmt<T>>> = vec![];
+        for stmt in ast {
+            match stmt.downcast_ref::<Use<T>>() {
+                Some(use_stmt) => {
+                    let module = self.load(use_stmt, path)?;
+                    linked.push(b!(use_stmt.clone().with_path(module)));
                 }
-            } else {
-                ast.push(stmt);
+                None => linked.push(stmt),
+            }
+        }
+        Ok(linked)
+    }
+
+    /// Reads and parses the file a `use` in `importer` points at, unless it
+    /// was loaded already, and returns its canonical path.
+    fn load(&mut self, use_stmt: &Use<T>, importer: &Path) -> Result<PathBuf> {
+        let (token, _) = use_stmt.extract();
+        let name = Exporter::module_path(use_stmt)?;
+        let unreadable = |err: std::io::Error| {
+            ExporterError::new(
+                token.clone(),
+                ExporterErrorType::CantReadModule(name.clone(), err.to_string()),
+            )
+        };
+
+        let Some(path) = self.find(&name, importer) else {
+            return Err(ExporterError::new(
+                token.clone(),
+                ExporterErrorType::ModuleNotFound(name.clone()),
+            )
+            .into());
+        };
+        let path = match bundled(&path) {
+            Some(_) => path,
+            None => path.canonicalize().map_err(unreadable)?,
+        };
+        if self.loading.contains(&path) {
+            let chain: Vec<_> = self
+                .loading
+                .iter()
+                .chain([&path])
+                .map(|path| self.display(path))
+                .collect();
+            return Err(ExporterError::new(
+                token.clone(),
+                ExporterErrorType::ImportCycle(chain.join(" -> ")),
+            )
+            .into());
+        }
+        if self.modules.contains_key(&path) {
+            return Ok(path);
+        }
+
+        let code = match bundled(&path) {
+            Some(code) => code.to_string(),
+            None => read_to_string(&path).map_err(unreadable)?,
+        };
+        let tokens = Scanner::new(&code).scan_tokens()?;
+        let ast = Parser::new(tokens).parse()?;
+        self.loading.push(path.clone());
+        let ast = self.link(ast, &path);
+        self.loading.pop();
+        self.modules.insert(path.clone(), ast?);
+        Ok(path)
+    }
+
+    /// Finds the file `name` refers to: a bundled `std/` module, otherwise
+    /// `name.yun` or `name/index.yun` next to the importer and then in each
+    /// search path.
+    fn find(&self, name: &str, importer: &Path) -> Option<PathBuf> {
+        let std_path = PathBuf::from(name.to_owned() + ".yun");
+        if bundled(&std_path).is_some() {
+            return Some(std_path);
+        }
+
+        let importer_dir = match bundled(importer) {
+            Some(_) => None,
+            None => importer.parent(),
+        };
+        importer_dir
+            .into_iter()
+            .chain(self.search_paths.iter().map(PathBuf::as_path))
+            .find_map(|dir| {
+                let base = dir.join(name);
+                [base.with_added_extension("yun"), base.join("index.yun")]
+                    .into_iter()
+                    .find(|path| path.is_file())
+            })
+    }
+
+    /// Splices the exports of plainly used modules into `ast`, each at most
+    /// once per `spliced`, and attaches a namespaced or selectively imported
+    /// module to its `use` as a program of its own. A plainly used module
+    /// with an export list is attached too, importing all its exports, as
+    /// the list may export a private declaration under another name.
+    fn flatten(
+        &self,
+        ast: Vec<Box<dyn Stmt<T>>>,
+        spliced: &mut HashSet<PathBuf>,
+    ) -> Result<Vec<Box<dyn Stmt<T>>>> {
+        let mut flat: Vec<Box<dyn Stmt<T>>> = vec![];
+        for stmt in ast {
+            let Some(use_stmt) = stmt.downcast_ref::<Use<T>>() else {
+                flat.push(stmt);
+                continue;
+            };
+            let path = use_stmt.get_path().unwrap();
+            let module = self.modules[path].clone();
+            match use_stmt.get_import() {
+                Import::All if !spliced.insert(path.clone()) => continue,
+                Import::All if Exporter::has_export_list(&module) => {
+                    let (token, _) = use_stmt.extract();
+                    let module = self.flatten(module, &mut HashSet::from([path.clone()]))?;
+                    let names = Exporter::exported_names(&module)
+                        .iter()
+                        .map(|name| {
+                            Token::new(
+                                TokenType::Identifier,
+                                name.get_lexeme(),
+                                None,
+                                token.get_line(),
+                                token.get_pos_in_line(),
+                            )
+                        })
+                        .collect();
+                    flat.push(b!(use_stmt
+                        .clone()
+                        .with_import(Import::Names(names))
+                        .with_module(module)));
+                }
+                Import::All => flat.extend(self.flatten(self.sift(module)?, spliced)?),
+                _ => {
+                    let module = self.flatten(module, &mut HashSet::from([path.clone()]))?;
+                    flat.push(b!(use_stmt.clone().with_module(module)));
+                }
+            }
+        }
+        Ok(flat)
+    }
+
+    /// `path` relative to the directory of the entry file where possible.
+    fn display(&self, path: &Path) -> String {
+        let root = self.loading.first().and_then(|root| root.parent());
+        root.and_then(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
+    /// The path string given to a `use`, as written.
+    pub fn module_path(use_stmt: &Use<T>) -> Result<String> {
+        let (name, expr) = use_stmt.extract();
+        match expr.downcast_ref::<Literal>().and_then(Literal::get_value) {
+            Some(Object::String(path)) => Ok(path.to_owned()),
+            _ => Err(ExporterError::new(
+                name.clone(),
+                ExporterErrorType::ExpectedPathStringAfterUse,
+            )
+            .into()),
+        }
+    }
+
+    /// Names exported by a flattened module, in order.
+    pub fn exported_names(ast: &[Box<dyn Stmt<T>>]) -> Vec<Token> {
+        let mut names = vec![];
+        for stmt in ast {
+            // `export * from` a module with an export list, attached by
+            // `flatten` as an import of all its exports.
+            if let Some(use_stmt) = stmt.downcast_ref::<Use<T>>()
+                && use_stmt.extract().0.get_type() == TokenType::Export
+                && let Import::Names(imported) = use_stmt.get_import()
+            {
+                names.extend(imported.iter().cloned());
+            }
+            let Some(export) = stmt.downcast_ref::<Export<T>>() else {
+                continue;
+            };
+            match export.extract() {
+                (_, Some(stmt)) => names.extend(Exporter::declared_names(stmt)),
+                (_, None) => names.extend(export.get_names().iter().map(|(_, name)| name.clone())),
             }
         }
-        Ok(ast)
+        names
     }
 
+    /// Whether a module exports existing bindings with `export { .. }`.
+    fn has_export_list(ast: &[Box<dyn Stmt<T>>]) -> bool {
+        ast.iter()
+            .filter_map(|stmt| stmt.downcast_ref::<Export<T>>())
+            .any(|export| export.extract().1.is_none())
+    }
+
+    /// Names a top-level declaration binds.
+    fn declared_names(stmt: &dyn Stmt<T>) -> Vec<Token> {
+        if let Some(stmt) = stmt.downcast_ref::<Let<T>>() {
+            match stmt.get_pattern() {
+                Some(pattern) => pattern.bindings().into_iter().cloned().collect(),
+                None => vec![stmt.get_ident()],
+            }
+        } else if let Some(stmt) = stmt.downcast_ref::<Fun<T>>() {
+            vec![stmt.get_name()]
+        } else if let Some(stmt) = stmt.downcast_ref::<Class<T>>() {
+            vec![stmt.extract().0.clone()]
+        } else if let Some(stmt) = stmt.downcast_ref::<Trait<T>>() {
+            vec![stmt.extract().0.clone()]
+        } else if let Some(stmt) = stmt.downcast_ref::<Enum>() {
+            vec![stmt.extract().0.clone()]
+        } else {
+            vec![]
+        }
+    }
+
+    /// Keeps the exported statements, along with the namespaced and
+    /// selective imports they may refer to.
     pub fn sift(&self, ast: Vec<Box<dyn Stmt<T>>>) -> Result<Vec<Box<dyn Stmt<T>>>> {
         let mut sifted_ast: Vec<Box<dyn Stmt<T>>> = vec![];
         for stmt in ast {
-            if let Some(export) = stmt.downcast_ref::<Export<T>>() {
-                sifted_ast.push(b!(export.clone()));
+            if stmt.downcast_ref::<Export<T>>().is_some() || stmt.downcast_ref::<Use<T>>().is_some()
+            {
+                sifted_ast.push(stmt);
             }
         }
         Ok(sifted_ast)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::error::InterpreterError;
+    use std::fs;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type Ast = Vec<Box<dyn Stmt<Result<Object>>>>;
+
+    /// A temporary directory of scripts, removed when dropped.
+    struct Project(PathBuf);
+
+    impl Project {
+        fn new(files: &[(&str, &str)]) -> Self {
+            static NEXT: AtomicUsize = AtomicUsize::new(0);
+            let dir = std::env::temp_dir().join(format!(
+                "yun-exporter-{}-{}",
+                std::process::id(),
+                NEXT.fetch_add(1, Ordering::Relaxed)
+            ));
+            for (name, code) in files {
+                let path = dir.join(name);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, code).unwrap();
+            }
+            Self(dir)
+        }
+
+        fn resolve(&self, entry: &str) -> Result<Ast> {
+            self.resolve_with(entry, &[])
+        }
+
+        /// Resolves `entry` with search paths relative to the project.
+        fn resolve_with(&self, entry: &str, search_paths: &[&str]) -> Result<Ast> {
+            let path = self.0.join(entry);
+            let code = fs::read_to_string(&path).unwrap();
+            let ast = Parser::new(Scanner::new(&code).scan_tokens()?).parse()?;
+            Exporter::new(path, ast)
+                .with_search_paths(search_paths.iter().map(|dir| self.0.join(dir)).collect())
+                .resolve()
+        }
+    }
+
+    impl Drop for Project {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn exporter_error(res: Result<Ast>) -> ExporterError {
+        match res {
+            Err(InterpreterError::ExporterError(err)) => err,
+            Err(err) => panic!("expected an exporter error, got: {}", err),
+            Ok(_) => panic!("expected an exporter error"),
+        }
+    }
+
+    fn exported(ast: &Ast) -> Vec<String> {
+        Exporter::exported_names(ast)
+            .iter()
+            .map(|name| name.get_lexeme().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn diamond_import_is_spliced_once() {
+        let project = Project::new(&[
+            ("main.yun", "use \"left\";\nuse \"right\";\n"),
+            ("left.yun", "use \"shared\";\nexport let left = 1;\n"),
+            ("right.yun", "use \"shared\";\nexport let right = 2;\n"),
+            ("shared.yun", "export let shared = 0;\n"),
+        ]);
+        let ast = project.resolve("main.yun").unwrap();
+        assert_eq!(exported(&ast), ["shared", "left", "right"]);
+    }
+
+    #[test]
+    fn import_cycle_reports_the_full_chain() {
+        let project = Project::new(&[("a.yun", "use \"b\";\n"), ("b.yun", "use \"a\";\n")]);
+        let err = exporter_error(project.resolve("a.yun"));
+        assert_eq!(
+            err.get_type(),
+            &ExporterErrorType::ImportCycle("a.yun -> b.yun -> a.yun".into())
+        );
+    }
+
+    #[test]
+    fn missing_module_points_at_the_use() {
+        let project = Project::new(&[("main.yun", "let a = 1;\nuse \"nowhere\";\n")]);
+        let err = exporter_error(project.resolve("main.yun"));
+        assert_eq!(
+            err.get_type(),
+            &ExporterErrorType::ModuleNotFound("nowhere".into())
+        );
+        assert_eq!(err.get_token().get_lexeme(), "use");
+        assert_eq!(err.get_token().get_line(), 2);
+    }
+
+    #[test]
+    fn directory_module_loads_its_index() {
+        let project = Project::new(&[
+            ("main.yun", "use \"pkg\";\n"),
+            ("pkg/index.yun", "export let pkg = 1;\n"),
+        ]);
+        let ast = project.resolve("main.yun").unwrap();
+        assert_eq!(exported(&ast), ["pkg"]);
+    }
+
+    #[test]
+    fn search_paths_come_after_the_importer_directory() {
+        let project = Project::new(&[
+            ("app/main.yun", "use \"local\";\nuse \"tool\";\n"),
+            ("app/local.yun", "export let nearby = 1;\n"),
+            ("first/local.yun", "export let shadowed = 1;\n"),
+            ("first/tool.yun", "export let first = 1;\n"),
+            ("second/tool.yun", "export let second = 1;\n"),
+        ]);
+        let ast = project
+            .resolve_with("app/main.yun", &["first", "second"])
+            .unwrap();
+        assert_eq!(exported(&ast), ["nearby", "first"]);
+    }
+
+    #[test]
+    fn bundled_std_module_needs_no_file() {
+        let project = Project::new(&[("main.yun", "use {add} from \"std/math\";\n")]);
+        assert!(project.resolve("main.yun").is_ok());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod ast;
+pub mod edition;
 pub mod environment;
 pub mod error;
 pub mod event;
@@ -10,14 +11,16 @@ pub mod scanner;
 pub mod shell;
 
 use crate::interpreter::ast::expr::anon_fun::AnonFun;
-use crate::interpreter::ast::expr::assignment::Assign;
+use crate::interpreter::ast::expr::assignment::{Assign, AssignOp};
 use crate::interpreter::ast::expr::binary::Binary;
 use crate::interpreter::ast::expr::call::Call;
 use crate::interpreter::ast::expr::get::{Get, GetType};
 use crate::interpreter::ast::expr::grouping::Grouping;
+use crate::interpreter::ast::expr::interpolation::Interpolation;
 use crate::interpreter::ast::expr::list::List;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::expr::logical::Logical;
+use crate::interpreter::ast::expr::match_expr::{ArmBody, Match, MatchArm};
 use crate::interpreter::ast::expr::object::Obj;
 use crate::interpreter::ast::expr::self_expr::SelfExpr;
 use crate::interpreter::ast::expr::set::{Set, SetType};
@@ -26,24 +29,35 @@ use crate::interpreter::ast::expr::unary::Unary;
 use crate::interpreter::ast::expr::variable::Variable;
 use crate::interpreter::ast::expr::{CloneExpr, Expr, ExprVisitor};
 use crate::interpreter::ast::stmt::block::Block;
+use crate::interpreter::ast::stmt::break_stmt::Break;
 use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::continue_stmt::Continue;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
+use crate::interpreter::ast::stmt::for_in_stmt::ForIn;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::ast::stmt::if_stmt::If;
 use crate::interpreter::ast::stmt::let_stmt::Let;
 use crate::interpreter::ast::stmt::print::Print;
 use crate::interpreter::ast::stmt::return_stmt::Return;
 use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
-use crate::interpreter::ast::stmt::use_stmt::Use;
+use crate::interpreter::ast::stmt::throw_stmt::Throw;
+use crate::interpreter::ast::stmt::trait_stmt::Trait;
+use crate::interpreter::ast::stmt::try_stmt::Try;
+use crate::interpreter::ast::stmt::use_stmt::{Import, Use};
 use crate::interpreter::ast::stmt::while_stmt::While;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::edition::Edition;
 use crate::interpreter::environment::Environment;
 use crate::interpreter::error::Result;
 use crate::interpreter::error::{InterpreterError, RuntimeError, RuntimeErrorType};
 use crate::interpreter::event::InterpreterEvent;
 use crate::interpreter::exporter::Exporter;
+use crate::interpreter::object::freeze::Freezable;
 use crate::interpreter::parser::Parser;
 use crate::interpreter::parser::resolver::{Resolver, SomeFun};
+use crate::interpreter::render_statement::pipeline_primitive;
+use crate::interpreter::render_statement::uniform_generator::validate_uniforms;
 use crate::interpreter::scanner::Scanner;
 use crate::interpreter::scanner::token::Token;
 use crate::interpreter::scanner::token::token_type::TokenType;
@@ -52,8 +66,10 @@ use crate::{b, rc};
 use glium::winit::event_loop::EventLoopProxy;
 use image::ImageReader;
 use object::Object;
-use object::callable::Callable;
+use object::callable::{Arity, Callable};
+use object::math::Math;
 use object::native_object::NativeObject;
+use object::sequence::Sequence;
 use std::collections::HashMap;
 use std::fs;
 use std::io::Cursor;
@@ -74,6 +90,17 @@ pub struct Interpreter {
     locals: Arc<RwLock<HashMap<u64, usize>>>,
     cancel_flag: Arc<AtomicBool>,
     must_call_channel: (Sender<Callable>, Arc<Mutex<Receiver<Callable>>>),
+    /// Set by a `?.`/`?[` step that hit nil, so the rest of its chain
+    /// evaluates to nil as well.
+    short_circuit: bool,
+    edition: Edition,
+    /// Exports of the modules run so far by canonical path, so a module
+    /// imported from several files runs once.
+    modules: HashMap<PathBuf, HashMap<String, Object>>,
+    /// Directories searched for modules not found next to the importer.
+    search_paths: Vec<PathBuf>,
+    /// Directories searched for images not found at the given path.
+    asset_paths: Vec<PathBuf>,
 }
 
 impl Interpreter {
@@ -86,9 +113,9 @@ impl Interpreter {
                 next_id(),
                 None,
                 None,
-                rc!(|_, args| {
+                rc!(|interpreter, args| {
                     if let Object::String(path) = args[0].clone() {
-                        let path = PathBuf::from(path);
+                        let path = interpreter.find_asset(PathBuf::from(path));
 
                         let content = fs::read(path).unwrap();
 
@@ -102,7 +129,7 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "image".into()),
                 false,
             ))),
@@ -115,11 +142,24 @@ impl Interpreter {
                 None,
                 None,
                 rc!(|interpreter, args| {
-                    let Object::String(key) = args[0].clone() else {
-                        return Ok(Object::Nil);
+                    let key = match args[0].inner() {
+                        Object::String(key) => Some(key.clone()),
+                        Object::Variant(key) => key.to_engine_string("Key"),
+                        _ => None,
+                    };
+                    let Some(key) = key else {
+                        return Err(RuntimeErrorType::InvalidArgument(
+                            "a Key value".into(),
+                            args[0].to_string(),
+                        )
+                        .into());
                     };
                     let Object::Callable(func) = args[1].clone() else {
-                        return Ok(Object::Nil);
+                        return Err(RuntimeErrorType::InvalidArgument(
+                            "a function".into(),
+                            args[1].get_type(),
+                        )
+                        .into());
                     };
 
                     interpreter
@@ -128,7 +168,7 @@ impl Interpreter {
 
                     Ok(Object::Nil)
                 }),
-                rc!(|| 2),
+                rc!(|| Arity::exact(2)),
                 rc!(|| "regKeyEvent".into()),
                 false,
             ))),
@@ -147,7 +187,7 @@ impl Interpreter {
                         .send_event(InterpreterEvent::GetWindowDimensions(tx))
                         .unwrap();
                     while let Ok(dimensions) = rx.recv() {
-                        return Ok(Object::Dictionary(Arc::new(RwLock::new({
+                        return Ok(Object::Dictionary(Arc::new(Freezable::new({
                             let mut h = HashMap::new();
                             h.insert("width".into(), Object::Number(dimensions.0 as f64));
                             h.insert("height".into(), Object::Number(dimensions.1 as f64));
@@ -156,7 +196,7 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 0),
+                rc!(|| Arity::exact(0)),
                 rc!(|| "getWindowDimensions".into()),
                 false,
             ))),
@@ -174,7 +214,7 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "tan".into()),
                 false,
             ))),
@@ -192,7 +232,7 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "sqrt".into()),
                 false,
             ))),
@@ -210,7 +250,7 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "sin".into()),
                 false,
             ))),
@@ -228,7 +268,7 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "rad".into()),
                 false,
             ))),
@@ -246,7 +286,7 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "deg".into()),
                 false,
             ))),
@@ -264,7 +304,7 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "cos".into()),
                 false,
             ))),
@@ -277,6 +317,18 @@ impl Interpreter {
                 None,
                 None,
                 rc!(|interpreter, args| {
+                    if let Object::List(list) = args[0].inner() {
+                        for elm in list.read().unwrap().iter() {
+                            if let Ok(pipeline) = elm.get_field(Object::Number(0.0)) {
+                                pipeline_primitive(&pipeline)?;
+                                if let Ok(uniforms) =
+                                    pipeline.get_field(Object::String("uniform".into()))
+                                {
+                                    validate_uniforms(&uniforms)?;
+                                }
+                            }
+                        }
+                    }
                     //println!("Call");
                     interpreter
                         .proxy
@@ -285,7 +337,7 @@ impl Interpreter {
                     std::thread::sleep(Duration::from_millis(10));
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "render".into()),
                 false,
             ))),
@@ -305,7 +357,7 @@ impl Interpreter {
                             .as_micros() as f64,
                     ))
                 }),
-                rc!(|| 0),
+                rc!(|| Arity::exact(0)),
                 rc!(|| "clock".into()),
                 false,
             ))),
@@ -318,7 +370,7 @@ impl Interpreter {
                 None,
                 None,
                 rc!(|_, args| Err(RuntimeErrorType::UserPanicWithMsg(args[0].clone()).into())),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "panic".into()),
                 false,
             ))),
@@ -330,8 +382,8 @@ impl Interpreter {
                 next_id(),
                 None,
                 None,
-                rc!(|_, args| Ok(Object::String(args[0].clone().to_string()))),
-                rc!(|| 1),
+                rc!(|interpreter, args| Ok(Object::String(interpreter.stringify(&args[0])?))),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "string".into()),
                 false,
             ))),
@@ -344,7 +396,7 @@ impl Interpreter {
                 None,
                 None,
                 rc!(|_, _| exit(0)),
-                rc!(|| 0),
+                rc!(|| Arity::exact(0)),
                 rc!(|| "exit".into()),
                 false,
             ))),
@@ -357,7 +409,7 @@ impl Interpreter {
                 None,
                 None,
                 rc!(|_, args| exit(Into::<Result<i32>>::into(args[0].clone())?)),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "exitWithCode".into()),
                 false,
             ))),
@@ -370,7 +422,7 @@ impl Interpreter {
                 None,
                 None,
                 rc!(|_, _| Ok(Object::NativeObject(NativeObject::new(b!(Instant::now()))))),
-                rc!(|| 0),
+                rc!(|| Arity::exact(0)),
                 rc!(|| "instant".into()),
                 false,
             ))),
@@ -390,12 +442,185 @@ impl Interpreter {
                     }
                     Ok(Object::Nil)
                 }),
-                rc!(|| 1),
+                rc!(|| Arity::exact(1)),
                 rc!(|| "elapsed".into()),
                 false,
             ))),
         );
 
+        // Sequence helpers. They accept lists, dictionaries, ranges, sequences
+        // and iterable instances; lists in give a list out, anything else
+        // gives a lazy sequence.
+        globals.define(
+            "map",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let sequence = Sequence::from_object(interpreter, args[0].clone())?;
+                    interpreter.finish_sequence(&args[..1], sequence.map(args[1].clone()))
+                }),
+                rc!(|| Arity::exact(2)),
+                rc!(|| "map".into()),
+                false,
+            ))),
+        );
+
+        globals.define(
+            "filter",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let sequence = Sequence::from_object(interpreter, args[0].clone())?;
+                    interpreter.finish_sequence(&args[..1], sequence.filter(args[1].clone()))
+                }),
+                rc!(|| Arity::exact(2)),
+                rc!(|| "filter".into()),
+                false,
+            ))),
+        );
+
+        globals.define(
+            "reduce",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let sequence = Sequence::from_object(interpreter, args[0].clone())?;
+                    let mut acc = args[2].clone();
+                    while let Some(value) = sequence.next(interpreter)? {
+                        acc = interpreter.call_object(args[1].clone(), vec![acc, value])?;
+                    }
+                    Ok(acc)
+                }),
+                rc!(|| Arity::exact(3)),
+                rc!(|| "reduce".into()),
+                false,
+            ))),
+        );
+
+        globals.define(
+            "zip",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let left = Sequence::from_object(interpreter, args[0].clone())?;
+                    let right = Sequence::from_object(interpreter, args[1].clone())?;
+                    interpreter.finish_sequence(&args, left.zip(right))
+                }),
+                rc!(|| Arity::exact(2)),
+                rc!(|| "zip".into()),
+                false,
+            ))),
+        );
+
+        globals.define(
+            "enumerate",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let sequence = Sequence::from_object(interpreter, args[0].clone())?;
+                    interpreter.finish_sequence(&args, sequence.enumerate())
+                }),
+                rc!(|| Arity::exact(1)),
+                rc!(|| "enumerate".into()),
+                false,
+            ))),
+        );
+
+        globals.define(
+            "take",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let sequence = Sequence::from_object(interpreter, args[0].clone())?;
+                    let n = match args[1].inner() {
+                        Object::Number(n) if *n >= 0.0 => *n as usize,
+                        _ => return Err(RuntimeErrorType::MustBeANumber.into()),
+                    };
+                    interpreter.finish_sequence(&args[..1], sequence.take(n))
+                }),
+                rc!(|| Arity::exact(2)),
+                rc!(|| "take".into()),
+                false,
+            ))),
+        );
+
+        // Makes a list or dictionary, and everything nested in it, immutable.
+        globals.define(
+            "freeze",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| {
+                    object::freeze::freeze(&args[0]);
+                    Ok(args[0].clone())
+                }),
+                rc!(|| Arity::exact(1)),
+                rc!(|| "freeze".into()),
+                false,
+            ))),
+        );
+
+        globals.define(
+            "isFrozen",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| Ok(Object::Bool(object::freeze::is_frozen(&args[0])))),
+                rc!(|| Arity::exact(1)),
+                rc!(|| "isFrozen".into()),
+                false,
+            ))),
+        );
+
+        globals.define(
+            "collect",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    Sequence::from_object(interpreter, args[0].clone())?.collect(interpreter)
+                }),
+                rc!(|| Arity::exact(1)),
+                rc!(|| "collect".into()),
+                false,
+            ))),
+        );
+
+        for value in object::enumeration::Enum::engine() {
+            let name = value.get_name().to_string();
+            globals.define(&name, Some(Object::Enum(value)));
+        }
+
+        for (name, arity, func) in Math::natives() {
+            globals.define(
+                name,
+                Some(Object::Callable(Callable::build(
+                    next_id(),
+                    None,
+                    None,
+                    rc!(move |_, args| func(&args)),
+                    rc!(move || arity),
+                    rc!(move || name.into()),
+                    false,
+                ))),
+            );
+        }
+
         let globals = Arc::new(RwLock::new(globals));
 
         let (tx, rx) = mpsc::channel();
@@ -408,9 +633,45 @@ impl Interpreter {
             locals: Default::default(),
             cancel_flag: Arc::new(AtomicBool::new(false)),
             must_call_channel: (tx, rc!(Mutex::new(rx))),
+            short_circuit: false,
+            edition: Edition::default(),
+            modules: HashMap::new(),
+            search_paths: vec![],
+            asset_paths: vec![],
         }
     }
 
+    pub fn with_edition(mut self, edition: Edition) -> Self {
+        self.edition = edition;
+        self
+    }
+
+    pub fn with_search_paths(mut self, search_paths: Vec<PathBuf>) -> Self {
+        self.search_paths = search_paths;
+        self
+    }
+
+    pub fn with_asset_paths(mut self, asset_paths: Vec<PathBuf>) -> Self {
+        self.asset_paths = asset_paths;
+        self
+    }
+
+    /// `path` itself if it exists, otherwise the first asset directory
+    /// containing it.
+    fn find_asset(&self, path: PathBuf) -> PathBuf {
+        if path.is_relative()
+            && !path.exists()
+            && let Some(found) = self
+                .asset_paths
+                .iter()
+                .map(|dir| dir.join(&path))
+                .find(|found| found.exists())
+        {
+            return found;
+        }
+        path
+    }
+
     pub fn get_must_call_handler(&self) -> Sender<Callable> {
         self.must_call_channel.0.clone()
     }
@@ -437,7 +698,10 @@ impl Interpreter {
         let mut parser: Parser<Result<Object>> = Parser::new(tokens);
         let ast = parser.parse()?;
 
-        let ast = Exporter::new(self.path.as_ref().clone(), ast).resolve()?;
+        let ast = Exporter::new(self.path.as_ref().clone(), ast)
+            .with_search_paths(self.search_paths.clone())
+            .resolve()?;
+        self.modules.clear();
 
         let mut resolver = Resolver::new(self);
 
@@ -514,7 +778,7 @@ impl Interpreter {
     }
 
     #[inline]
-    fn handle_runtime_error(token: Token, res: Result<Object>) -> Result<Object> {
+    fn handle_runtime_error<T>(token: Token, res: Result<T>) -> Result<T> {
         if let Err(err) = res {
             if let InterpreterError::RuntimeErrorType(runtime_ty) = err {
                 return match runtime_ty {
@@ -534,6 +798,357 @@ impl Interpreter {
         Interpreter::report("Panic", token.get_line(), token.get_pos_in_line(), "", msg)
     }
 
+    /// Evaluates the object of a property access or the callee of a call.
+    /// `None` means the step short-circuits: either an optional step
+    /// earlier in the same chain hit nil, or this step is optional and its
+    /// object is nil.
+    fn evaluate_link(
+        &mut self,
+        expr: &dyn Expr<Result<Object>>,
+        optional: bool,
+    ) -> Result<Option<Object>> {
+        let value = self.evaluate(expr)?;
+        let is_link = expr.downcast_ref::<Get<Result<Object>>>().is_some()
+            || expr.downcast_ref::<Call<Result<Object>>>().is_some();
+        if (is_link && self.short_circuit)
+            || (optional && matches!(value.inner(), Object::Nil | Object::Void))
+        {
+            self.short_circuit = true;
+            return Ok(None);
+        }
+        Ok(Some(value))
+    }
+
+    /// Turns method declarations into callables closing over the current
+    /// environment, keyed by name.
+    fn functions(&self, funs: &[Fun<Result<Object>>], allow_init: bool) -> HashMap<String, Object> {
+        funs.iter()
+            .map(|fun| {
+                let name = fun.get_name().get_lexeme().to_string();
+                let is_init = allow_init && name == "init";
+                let func = Object::function(SomeFun::Fun(fun.clone()), self.env.clone(), is_init);
+                (name, func)
+            })
+            .collect()
+    }
+
+    fn get_property(&mut self, obj: Object, name: &Token) -> Result<Object> {
+        match obj.inner() {
+            Object::Instance(instance) => instance.get(self, name),
+            Object::Dictionary(_) | Object::Class(_) | Object::Enum(_) | Object::Variant(_) => {
+                self.get_index(obj, name, Object::String(name.get_lexeme().into()))
+            }
+            Object::Math(math) => {
+                Interpreter::handle_runtime_error(name.clone(), math.get(name.get_lexeme()))
+            }
+            Object::List(_) => Interpreter::handle_runtime_error(
+                name.clone(),
+                object::list::method(&obj, name.get_lexeme()),
+            ),
+            _ => Err(RuntimeError::new(
+                name.clone(),
+                RuntimeErrorType::OnlyInstancesHaveProperties,
+            )
+            .into()),
+        }
+    }
+
+    fn get_index(&mut self, obj: Object, token: &Token, index: Object) -> Result<Object> {
+        let res = match obj.inner() {
+            Object::Instance(instance) => index
+                .as_key()
+                .and_then(|key| instance.get_property(self, &key)),
+            _ => obj.get_field(index),
+        };
+        Interpreter::handle_runtime_error(token.clone(), res)
+    }
+
+    fn set_index(
+        &mut self,
+        obj: Object,
+        token: &Token,
+        index: Object,
+        value: Object,
+    ) -> Result<()> {
+        let res = match obj.inner() {
+            Object::Instance(instance) => index
+                .as_key()
+                .and_then(|key| instance.set_property(self, &key, value)),
+            _ => obj.set_field(index, value),
+        };
+        Interpreter::handle_runtime_error(token.clone(), res)
+    }
+
+    /// Combines the target's `current` value with the right-hand side according
+    /// to `op`, returning the value to store and the value of the expression.
+    fn apply_assign_op(
+        &mut self,
+        op: &AssignOp,
+        current: Object,
+        value: Object,
+    ) -> Result<(Object, Object)> {
+        let (token, postfix) = match op {
+            AssignOp::Plain => return Ok((value.clone(), value)),
+            AssignOp::Compound(token) => (token, false),
+            AssignOp::Postfix(token) => (token, true),
+        };
+
+        let binary_op = match token.get_type() {
+            TokenType::PlusEqual | TokenType::PlusPlus => TokenType::Plus,
+            TokenType::MinusEqual | TokenType::MinusMinus => TokenType::Minus,
+            TokenType::StarEqual => TokenType::Star,
+            TokenType::SlashEqual => TokenType::Slash,
+            TokenType::PercentEqual => TokenType::Percent,
+            _ => {
+                return Err(RuntimeError::new(
+                    token.clone(),
+                    RuntimeErrorType::UnsupportedBinaryOperator(token.get_lexeme().into()),
+                )
+                .into());
+            }
+        };
+        let res = self.binary_op(binary_op, token.get_lexeme(), current.clone(), value);
+        let updated = Interpreter::handle_runtime_error(token.clone(), res)?;
+
+        if postfix {
+            Ok((updated, current))
+        } else {
+            Ok((updated.clone(), updated))
+        }
+    }
+
+    /// Applies a binary operator. Arithmetic and comparisons dispatch to the
+    /// operator methods (`add`, `sub`, `mul`, `div`, `lt`) of a class instance
+    /// on the left; `>`, `<=` and `>=` are derived from `lt` and `eq`.
+    /// Otherwise an instance on the right is asked: `left + right` calls
+    /// `right.radd(left)`, and likewise `rsub`, `rmul` and `rdiv`, while a
+    /// comparison is mirrored, so `left < right` becomes `right > left`.
+    fn binary_op(
+        &mut self,
+        op: TokenType,
+        lexeme: &str,
+        left: Object,
+        right: Object,
+    ) -> Result<Object> {
+        let (method, reflected) = match op {
+            TokenType::Plus => (Some("add"), Some("radd")),
+            TokenType::Minus => (Some("sub"), Some("rsub")),
+            TokenType::Star => (Some("mul"), Some("rmul")),
+            TokenType::Slash => (Some("div"), Some("rdiv")),
+            TokenType::Less
+            | TokenType::Greater
+            | TokenType::LessEqual
+            | TokenType::GreaterEqual => (Some("lt"), None),
+            _ => (None, None),
+        };
+        if let Some(method) = method
+            && let Some(res) = self.call_operator(&left, method, vec![right.clone()])?
+        {
+            return Ok(match op {
+                TokenType::Less => Object::Bool(self.is_truly(&res)?),
+                TokenType::GreaterEqual => Object::Bool(!self.is_truly(&res)?),
+                TokenType::Greater => {
+                    Object::Bool(!self.is_truly(&res)? && !self.objects_equal(&left, &right)?)
+                }
+                TokenType::LessEqual => {
+                    Object::Bool(self.is_truly(&res)? || self.objects_equal(&left, &right)?)
+                }
+                _ => res,
+            });
+        }
+        if let Some(reflected) = reflected
+            && let Some(res) = self.call_operator(&right, reflected, vec![left.clone()])?
+        {
+            return Ok(res);
+        }
+        let mirrored = match op {
+            TokenType::Less => Some(TokenType::Greater),
+            TokenType::Greater => Some(TokenType::Less),
+            TokenType::LessEqual => Some(TokenType::GreaterEqual),
+            TokenType::GreaterEqual => Some(TokenType::LessEqual),
+            _ => None,
+        };
+        if let Some(mirrored) = mirrored
+            && Interpreter::defines_operator(&right, "lt")
+        {
+            return self.binary_op(mirrored, lexeme, right, left);
+        }
+
+        match op {
+            TokenType::EqualEqual => Ok(Object::Bool(self.objects_equal(&left, &right)?)),
+            TokenType::BangEqual => Ok(Object::Bool(!self.objects_equal(&left, &right)?)),
+            TokenType::Greater => Ok(Object::Bool(left > right)),
+            TokenType::Less => Ok(Object::Bool(left < right)),
+            TokenType::GreaterEqual => Ok(Object::Bool(left >= right)),
+            TokenType::LessEqual => Ok(Object::Bool(left <= right)),
+            TokenType::Plus if self.edition >= Edition::E2026 => left.concat(right),
+            TokenType::Plus => left + right,
+            TokenType::Minus => left - right,
+            TokenType::Star => left * right,
+            TokenType::Slash => left / right,
+            TokenType::Percent => left % right,
+            TokenType::StarStar => left.pow(right),
+            TokenType::Ampersand => left & right,
+            TokenType::Pipe => left | right,
+            TokenType::Caret => left ^ right,
+            TokenType::LessLess => left << right,
+            TokenType::GreaterGreater => left >> right,
+            TokenType::DotDot => left.range(right),
+            _ => Err(RuntimeErrorType::UnsupportedBinaryOperator(lexeme.into()).into()),
+        }
+    }
+
+    /// `==`, asking the `eq` method of an instance on either side first.
+    fn objects_equal(&mut self, left: &Object, right: &Object) -> Result<bool> {
+        if let Some(res) = self.call_operator(left, "eq", vec![right.clone()])? {
+            return self.is_truly(&res);
+        }
+        if let Some(res) = self.call_operator(right, "eq", vec![left.clone()])? {
+            return self.is_truly(&res);
+        }
+        Ok(left == right)
+    }
+
+    /// Text of a value for `print`, `string()` and interpolation, using the
+    /// `str` method of an instance that defines one.
+    fn stringify(&mut self, obj: &Object) -> Result<String> {
+        match self.call_operator(obj, "str", vec![])? {
+            Some(res) => Ok(res.to_string()),
+            None => Ok(obj.to_string()),
+        }
+    }
+
+    /// Calls the operator method `name` when `obj` is an instance whose class
+    /// defines it; `None` otherwise.
+    fn call_operator(
+        &mut self,
+        obj: &Object,
+        name: &str,
+        args: Vec<Object>,
+    ) -> Result<Option<Object>> {
+        let Object::Instance(instance) = obj.inner() else {
+            return Ok(None);
+        };
+        match instance.get_method(name)? {
+            Some(method) => self.call_object(method, args).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn defines_operator(obj: &Object, name: &str) -> bool {
+        match obj.inner() {
+            Object::Instance(instance) => instance.get_method(name).is_ok_and(|m| m.is_some()),
+            _ => false,
+        }
+    }
+
+    /// Evaluates an expression in the given environment, e.g. a parameter
+    /// default in the scope of the call being set up.
+    fn evaluate_in(
+        &mut self,
+        environment: Arc<RwLock<Environment>>,
+        expr: &dyn Expr<Result<Object>>,
+    ) -> Result<Object> {
+        let previous = self.env.replace(environment);
+        let res = self.evaluate(expr);
+        self.env.replace(previous.unwrap());
+        res
+    }
+
+    /// Calls a function or class value with already evaluated arguments.
+    /// Errors carry no position; callers attach the token of the call site.
+    fn call_object(&mut self, callable: Object, args: Vec<Object>) -> Result<Object> {
+        self.call_with_named(callable, args, vec![])
+    }
+
+    /// Like `call_object`, but also places `name: value` arguments into the
+    /// slots of the parameters they name. Skipped slots hold `Void`, which
+    /// the callee treats as "not given" and fills with the default.
+    fn call_with_named(
+        &mut self,
+        callable: Object,
+        mut args: Vec<Object>,
+        named: Vec<(Token, Object)>,
+    ) -> Result<Object> {
+        let callable = match callable.clone_into_rc() {
+            Object::Class(class) => Object::Callable((*class).into()),
+            _ => callable,
+        };
+
+        let Object::Callable(callable) = callable else {
+            return Err(RuntimeErrorType::NotCallable.into());
+        };
+
+        if !named.is_empty() {
+            let params = callable.get_params();
+            let arity = callable.arity();
+            for (name, value) in named {
+                let lexeme = name.get_lexeme();
+                let position = params.iter().position(|param| param == lexeme);
+                // The `...rest` parameter only collects positional arguments.
+                let Some(i) = position.filter(|&i| !arity.is_variadic() || i + 1 < params.len())
+                else {
+                    return Err(RuntimeError::new(
+                        name.clone(),
+                        RuntimeErrorType::UnknownParameter(lexeme.into()),
+                    )
+                    .into());
+                };
+                if args.len() <= i {
+                    args.resize(i + 1, Object::Void);
+                }
+                if !matches!(args[i], Object::Void) {
+                    return Err(RuntimeError::new(
+                        name.clone(),
+                        RuntimeErrorType::ArgumentGivenTwice(lexeme.into()),
+                    )
+                    .into());
+                }
+                args[i] = value;
+            }
+        }
+
+        let arity = callable.arity();
+        if !arity.accepts(args.len()) {
+            return Err(RuntimeErrorType::ArityMismatch(arity, args.len()).into());
+        }
+        callable._call(self, args)
+    }
+
+    /// Result of a sequence helper: collected into a list when every source
+    /// was a list, left lazy otherwise.
+    fn finish_sequence(&mut self, sources: &[Object], sequence: Sequence) -> Result<Object> {
+        if sources
+            .iter()
+            .all(|source| matches!(source.inner(), Object::List(_)))
+        {
+            sequence.collect(self)
+        } else {
+            Ok(sequence.into())
+        }
+    }
+
+    /// Runs a match arm whose pattern matched, in the environment holding its
+    /// bindings. `None` means the guard rejected the arm.
+    fn run_arm(&mut self, arm: &MatchArm<Result<Object>>) -> Result<Option<Object>> {
+        if let Some(guard) = arm.get_guard() {
+            let guard = self.evaluate(guard)?;
+            if !self.is_truly(&guard)? {
+                return Ok(None);
+            }
+        }
+        match arm.get_body() {
+            ArmBody::Expr(expr) => self.evaluate(expr.deref()).map(Some),
+            ArmBody::Block(stmts) => {
+                self.execute_block(
+                    stmts.iter().map(AsRef::as_ref).collect(),
+                    Arc::new(RwLock::new(Environment::new(self.env.clone()))),
+                )?;
+                Ok(Some(Object::Nil))
+            }
+        }
+    }
+
     fn is_truly(&self, obj: &Object) -> Result<bool> {
         Ok((!(!obj)?)? == Object::Bool(true))
     }
@@ -583,24 +1198,7 @@ impl ExprVisitor<Result<Object>> for Interpreter {
         let left = self.evaluate(binary.get_left())?;
         let right = self.evaluate(binary.get_right())?;
 
-        let res = match binary.get_op_type() {
-            TokenType::EqualEqual => Ok(Object::Bool(left == right)),
-            TokenType::BangEqual => Ok(Object::Bool(left != right)),
-            TokenType::Greater => Ok(Object::Bool(left > right)),
-            TokenType::Less => Ok(Object::Bool(left < right)),
-            TokenType::GreaterEqual => Ok(Object::Bool(left >= right)),
-            TokenType::LessEqual => Ok(Object::Bool(left <= right)),
-            TokenType::Plus => left + right,
-            TokenType::Minus => left - right,
-            TokenType::Star => left * right,
-            TokenType::Slash => left / right,
-            _ => Err(RuntimeError::new(
-                binary.get_token(),
-                RuntimeErrorType::UnsupportedBinaryOperator(binary.get_op_lexeme().into()),
-            )
-            .into()),
-        };
-
+        let res = self.binary_op(binary.get_op_type(), binary.get_op_lexeme(), left, right);
         Interpreter::handle_runtime_error(binary.get_token(), res)
     }
 
@@ -615,8 +1213,13 @@ impl ExprVisitor<Result<Object>> for Interpreter {
     fn visit_unary(&mut self, unary: &Unary<Result<Object>>) -> Result<Object> {
         let obj = self.evaluate(unary.get_right())?;
         let res = match unary.get_op_type() {
-            TokenType::Minus => -obj,
+            TokenType::Minus => match self.call_operator(&obj, "neg", vec![]) {
+                Ok(Some(res)) => Ok(res),
+                Ok(None) => -obj,
+                Err(err) => Err(err),
+            },
             TokenType::Bang => !obj,
+            TokenType::Tilde => obj.bit_not(),
             _ => Err(RuntimeError::new(
                 unary.get_token(),
                 RuntimeErrorType::UnsupportedBinaryOperator(unary.get_op_lexeme().into()),
@@ -632,8 +1235,16 @@ impl ExprVisitor<Result<Object>> for Interpreter {
     }
 
     fn visit_assign(&mut self, assign: &Assign<Result<Object>>) -> Result<Object> {
-        let value = self.evaluate(assign.get_value())?;
         let name = assign.get_token();
+        let op = assign.get_op();
+        let current = if op.is_plain() {
+            Object::Nil
+        } else {
+            self.look_up_variable(name.clone(), assign)?
+        };
+        let value = self.evaluate(assign.get_value())?;
+        let (value, result) = self.apply_assign_op(op, current, value)?;
+
         if let Some(distance) = self
             .locals
             .read()
@@ -641,18 +1252,25 @@ impl ExprVisitor<Result<Object>> for Interpreter {
             .get(&assign.clone_expr().id())
             .cloned()
         {
-            Environment::assign_at(self.env.clone(), distance, &name, value)
+            Environment::assign_at(self.env.clone(), distance, &name, value)?;
         } else {
-            if let Some(globals) = self.globals.clone() {
-                return globals.write().unwrap().assign(&name, value);
-            }
-            Err(RuntimeError::new(name, RuntimeErrorType::BugEnvironmentNotInit).into())
+            let Some(globals) = self.globals.clone() else {
+                return Err(
+                    RuntimeError::new(name, RuntimeErrorType::BugEnvironmentNotInit).into(),
+                );
+            };
+            globals.write().unwrap().assign(&name, value)?;
         }
+        Ok(result)
     }
 
     fn visit_logical(&mut self, logical: &Logical<Result<Object>>) -> Result<Object> {
         let left = self.evaluate(logical.get_left())?;
-        if logical.get_operator().get_type().eq(&TokenType::Or) {
+        if logical.get_operator().get_type() == TokenType::QuestionQuestion {
+            if !matches!(left.inner(), Object::Nil | Object::Void) {
+                return Ok(left);
+            }
+        } else if logical.get_operator().get_type().eq(&TokenType::Or) {
             if self.is_truly(&left)? {
                 return Ok(left);
             }
@@ -664,124 +1282,72 @@ impl ExprVisitor<Result<Object>> for Interpreter {
     }
 
     fn visit_call(&mut self, call_: &Call<Result<Object>>) -> Result<Object> {
-        let callable = self.evaluate(call_.get_callable())?;
+        let Some(callable) = self.evaluate_link(call_.get_callable(), call_.is_optional())? else {
+            return Ok(Object::Nil);
+        };
         let mut args: Vec<Object> = Vec::new();
         for arg in call_.get_args() {
             args.push(self.evaluate(arg)?);
         }
-
-        let callable = match callable.clone_into_rc() {
-            Object::Class(class) => Object::Callable((*class).into()),
-            _ => callable,
-        };
-
-        match callable {
-            Object::Callable(callable) => {
-                if args.len() != callable.arity() {
-                    return Err(RuntimeError::new(
-                        call_.get_token(),
-                        RuntimeErrorType::ArityOfFuncNotEqSizeOfArgs,
-                    )
-                    .into());
-                }
-                Interpreter::handle_runtime_error(call_.get_token(), callable._call(self, args))
-            }
-            _ => Err(RuntimeError::new(call_.get_token(), RuntimeErrorType::NotCallable).into()),
+        let mut named = Vec::new();
+        for (name, arg) in call_.get_named() {
+            named.push((name.clone(), self.evaluate(arg)?));
         }
+
+        let res = self.call_with_named(callable, args, named);
+        self.short_circuit = false;
+        Interpreter::handle_runtime_error(call_.get_token(), res)
     }
 
     fn visit_get(&mut self, get: &Get<Result<Object>>) -> Result<Object> {
         let (ty, obj) = get.extract();
-        let obj = self.evaluate(obj)?;
-        match ty {
-            GetType::Name(name) => {
-                match obj {
-                    Object::Instance(instance) => return instance.get(name),
-                    Object::Dictionary(dict) => {
-                        return Ok(dict
-                            .read()
-                            .unwrap()
-                            .get(name.get_lexeme())
-                            .unwrap_or(
-                                dict.read()
-                                    .unwrap()
-                                    .get(&format!(r#""{}""#, name.get_lexeme()))
-                                    .unwrap_or(&Object::Nil),
-                            )
-                            .clone());
-                    }
-                    _ => {}
-                }
-                Err(
-                    RuntimeError::new(name.clone(), RuntimeErrorType::OnlyInstancesHaveProperties)
-                        .into(),
-                )
-            }
+        let Some(obj) = self.evaluate_link(obj, get.is_optional())? else {
+            return Ok(Object::Nil);
+        };
+        let res = match ty {
+            GetType::Name(name) => self.get_property(obj, name),
             GetType::Index(token, index) => {
-                let Object::Number(index) = self.evaluate(index.clone().deref())? else {
-                    return Err(
-                        RuntimeError::new(token.clone(), RuntimeErrorType::MustBeANumber).into(),
-                    );
-                };
-                let Object::List(_) = &obj else {
-                    return Err(RuntimeError::new(
-                        token.clone(),
-                        RuntimeErrorType::OnlyListsHaveIndices,
-                    )
-                    .into());
-                };
-                Ok(obj.get_field(Object::Number(index)).unwrap())
+                let index = self.evaluate(index.clone().deref())?;
+                self.get_index(obj, token, index)
             }
-        }
-        //println!("{name}");
-
-        //println!("Eval");
+        };
+        self.short_circuit = false;
+        res
     }
 
     fn visit_set(&mut self, set: &Set<Result<Object>>) -> Result<Object> {
-        let (ty, obj, value) = set.extract();
+        let (ty, obj, op, value) = set.extract();
         let obj = self.evaluate(obj)?;
 
-        match ty {
+        let (token, index) = match ty {
             SetType::Name(name) => {
-                match obj {
-                    Object::Instance(instance) => {
-                        let value = self.evaluate(value)?;
-                        instance.set(name, value.clone());
-                        return Ok(value);
-                    }
-                    Object::Dictionary(dict) => {
-                        let value = self.evaluate(value)?;
-                        dict.write()
-                            .unwrap()
-                            .insert(name.get_lexeme().into(), value.clone());
-                        return Ok(value);
-                    }
-                    _ => {}
-                }
-                Err(
-                    RuntimeError::new(name.clone(), RuntimeErrorType::OnlyInstancesHaveProperties)
-                        .into(),
-                )
-            }
-            SetType::Index(token, index) => {
-                let Object::Number(index) = self.evaluate(index.clone().deref())? else {
-                    return Err(
-                        RuntimeError::new(token.clone(), RuntimeErrorType::MustBeANumber).into(),
-                    );
-                };
-                let Object::List(list) = &obj else {
+                if !matches!(
+                    obj.inner(),
+                    Object::Instance(_)
+                        | Object::Dictionary(_)
+                        | Object::Class(_)
+                        | Object::Math(_)
+                ) {
                     return Err(RuntimeError::new(
-                        token.clone(),
-                        RuntimeErrorType::OnlyListsHaveIndices,
+                        name.clone(),
+                        RuntimeErrorType::OnlyInstancesHaveProperties,
                     )
                     .into());
-                };
-                let value = self.evaluate(value)?;
-                list.write().unwrap()[index as usize] = value.clone();
-                Ok(value)
+                }
+                (name, Object::String(name.get_lexeme().into()))
             }
-        }
+            SetType::Index(token, index) => (token, self.evaluate(index.clone().deref())?),
+        };
+
+        let current = if op.is_plain() {
+            Object::Nil
+        } else {
+            self.get_index(obj.clone(), token, index.clone())?
+        };
+        let value = self.evaluate(value)?;
+        let (value, result) = self.apply_assign_op(op, current, value)?;
+        self.set_index(obj, token, index, value)?;
+        Ok(result)
     }
 
     fn visit_self(&mut self, self_val: &SelfExpr) -> Result<Object> {
@@ -827,7 +1393,7 @@ impl ExprVisitor<Result<Object>> for Interpreter {
         for val in list.extract_values() {
             values.push(self.evaluate(val)?);
         }
-        Ok(Object::List(rc!(RwLock::new(values))))
+        Ok(Object::List(rc!(Freezable::new(values))))
     }
 
     fn visit_object(&mut self, object: &Obj<Result<Object>>) -> Result<Object> {
@@ -836,13 +1402,55 @@ impl ExprVisitor<Result<Object>> for Interpreter {
         for (key, value) in values.clone() {
             obj.insert(key.get_lexeme().to_string(), self.evaluate(value.deref())?);
         }
-        Ok(Object::Dictionary(rc!(RwLock::new(obj))))
+        Ok(Object::Dictionary(rc!(Freezable::new(obj))))
     }
 
     fn visit_anon(&mut self, anon: &AnonFun<Result<Object>>) -> Result<Object> {
         let anon = Object::function(SomeFun::AnonFun(anon.clone()), self.env.clone(), false);
         Ok(anon)
     }
+
+    fn visit_interpolation(
+        &mut self,
+        interpolation: &Interpolation<Result<Object>>,
+    ) -> Result<Object> {
+        let mut value = String::new();
+        for part in interpolation.get_parts() {
+            let part = self.evaluate(part)?;
+            value += &self.stringify(&part)?;
+        }
+        Ok(Object::String(value))
+    }
+
+    fn visit_match(&mut self, match_expr: &Match<Result<Object>>) -> Result<Object> {
+        let subject = self.evaluate(match_expr.get_subject())?;
+
+        for arm in match_expr.get_arms() {
+            let mut bindings = vec![];
+            let matched = arm.get_pattern().matches(self, &subject, &mut bindings);
+            if !Interpreter::handle_runtime_error(match_expr.get_keyword(), matched)? {
+                continue;
+            }
+
+            let mut env = Environment::new(self.env.clone());
+            for (name, value) in bindings {
+                env.define(name.get_lexeme(), Some(value));
+            }
+            let previous = self.env.replace(Arc::new(RwLock::new(env)));
+            let res = self.run_arm(arm);
+            self.env = previous;
+
+            if let Some(value) = res? {
+                return Ok(value);
+            }
+        }
+
+        Err(RuntimeError::new(
+            match_expr.get_keyword(),
+            RuntimeErrorType::NoMatchingArm(subject.to_string()),
+        )
+        .into())
+    }
 }
 
 impl StmtVisitor<Result<Object>> for Interpreter {
@@ -852,11 +1460,35 @@ impl StmtVisitor<Result<Object>> for Interpreter {
 
     fn visit_print(&mut self, stmt: &Print<Result<Object>>) -> Result<Object> {
         let value = self.evaluate(stmt.expr())?;
-        println!("{}", value);
+        println!("{}", self.stringify(&value)?);
         Ok(Object::Nil)
     }
 
     fn visit_let(&mut self, stmt: &Let<Result<Object>>) -> Result<Object> {
+        if let (Some(pattern), Some(initializer)) = (stmt.get_pattern(), stmt.get_initializer()) {
+            let value = self.evaluate(initializer)?;
+            let mut bindings = vec![];
+            let matched = pattern.matches(self, &value, &mut bindings);
+            if !Interpreter::handle_runtime_error(stmt.get_ident(), matched)? {
+                return Err(RuntimeError::new(
+                    stmt.get_ident(),
+                    RuntimeErrorType::PatternMismatch(value.to_string(), pattern.to_string()),
+                )
+                .into());
+            }
+            let Some(env) = &self.env else {
+                return Err(RuntimeError::new(
+                    stmt.get_ident(),
+                    RuntimeErrorType::BugEnvironmentNotInit,
+                )
+                .into());
+            };
+            for (name, value) in bindings {
+                env.write().unwrap().define(name.get_lexeme(), Some(value));
+            }
+            return Ok(Object::Nil);
+        }
+
         match stmt.get_initializer() {
             Some(initializer) => {
                 let value = self.evaluate(initializer)?;
@@ -914,15 +1546,63 @@ impl StmtVisitor<Result<Object>> for Interpreter {
     }
 
     fn visit_while(&mut self, stmt: &While<Result<Object>>) -> Result<Object> {
-        let (cond, stmt) = stmt.extract();
+        let (cond, stmt, increment) = stmt.extract();
         let mut evaluated_cond = self.evaluate(cond)?;
         while self.is_truly(&evaluated_cond)? {
-            self.execute(stmt)?;
+            match self.execute(stmt) {
+                Ok(_) | Err(InterpreterError::Continue) => {}
+                Err(InterpreterError::Break) => break,
+                Err(err) => return Err(err),
+            }
+            if let Some(increment) = increment {
+                self.evaluate(increment)?;
+            }
             evaluated_cond = self.evaluate(cond)?;
         }
         Ok(Object::Nil)
     }
 
+    fn visit_for_in(&mut self, stmt: &ForIn<Result<Object>>) -> Result<Object> {
+        let (names, keyword, iterable, body) = stmt.extract();
+        let iterable = self.evaluate(iterable)?;
+        let sequence = match names {
+            [_, _]
+                if matches!(
+                    iterable.inner(),
+                    Object::List(_) | Object::Dictionary(_) | Object::Range(..)
+                ) =>
+            {
+                Sequence::pairs(&iterable)
+            }
+            _ => Sequence::from_object(self, iterable),
+        };
+        let sequence = Interpreter::handle_runtime_error(keyword.clone(), sequence)?;
+
+        loop {
+            let item = Interpreter::handle_runtime_error(keyword.clone(), sequence.next(self))?;
+            let Some(item) = item else {
+                break;
+            };
+            let mut env = Environment::new(self.env.clone());
+            match names {
+                [key_name, value_name] => {
+                    let (key, value) =
+                        Interpreter::handle_runtime_error(keyword.clone(), item.as_pair())?;
+                    env.define(key_name.get_lexeme(), Some(key));
+                    env.define(value_name.get_lexeme(), Some(value));
+                }
+                [name] => env.define(name.get_lexeme(), Some(item)),
+                _ => unreachable!(),
+            }
+            match self.execute_block(vec![body], Arc::new(RwLock::new(env))) {
+                Ok(_) | Err(InterpreterError::Continue) => {}
+                Err(InterpreterError::Break) => break,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(Object::Nil)
+    }
+
     fn visit_fun(&mut self, stmt: &Fun<Result<Object>>) -> Result<Object> {
         let name = stmt.get_name();
         let func = Object::function(SomeFun::Fun(stmt.clone()), self.env.clone(), false);
@@ -975,6 +1655,19 @@ impl StmtVisitor<Result<Object>> for Interpreter {
                 None
             };
 
+            let mut mixins = Vec::with_capacity(class.get_mixins().len());
+            for mixin in class.get_mixins() {
+                let value = self.evaluate(mixin)?;
+                if !matches!(value.inner(), Object::Trait(_)) {
+                    return Err(RuntimeError::new(
+                        mixin.get_token(),
+                        RuntimeErrorType::MixinMustBeTrait,
+                    )
+                    .into());
+                }
+                mixins.push(value);
+            }
+
             if let Some(superclass) = superclass.clone() {
                 self.env = Some(Arc::new(RwLock::new(Environment::new(self.env.clone()))));
                 self.env
@@ -987,37 +1680,209 @@ impl StmtVisitor<Result<Object>> for Interpreter {
 
             env.write().unwrap().define(name.get_lexeme(), None);
 
-            let mut methods_ = HashMap::with_capacity(methods.len());
-
-            for method in methods {
-                let name = method.get_name();
-                let func = Object::function(
-                    SomeFun::Fun(method.clone()),
-                    self.env.clone(),
-                    method.get_name().get_lexeme().eq("init"),
-                );
-                methods_.insert(name.get_lexeme().to_string(), func);
+            let methods_ = self.functions(methods, true);
+            let getters = self.functions(class.get_getters(), false);
+            let setters = self.functions(class.get_setters(), false);
+            let fields = class.get_fields().map(|fields| {
+                Object::function(SomeFun::Fun(fields.clone()), self.env.clone(), false)
+            });
+
+            let class_ = object::class::Class::new(
+                name.get_lexeme().to_string(),
+                methods_,
+                superclass.clone(),
+            )
+            .with_mixins(mixins)
+            .with_accessors(getters, setters)
+            .with_fields(fields);
+
+            // Static members see the class itself as `self`.
+            let mut statics_env = Environment::new(self.env.clone());
+            let class_obj = Object::Arc(rc!(Object::Class(b!(class_.clone()))));
+            statics_env.define("self", Some(class_obj.clone()));
+            let statics_env = Some(Arc::new(RwLock::new(statics_env)));
+            for method in class.get_statics() {
+                let func =
+                    Object::function(SomeFun::Fun(method.clone()), statics_env.clone(), false);
+                class_.set_static(method.get_name().get_lexeme(), func);
             }
 
-            let class = Object::class(name.get_lexeme(), methods_, superclass.clone());
-
             if superclass.is_some() {
                 self.env = self.env.clone().unwrap().read().unwrap().get_enclosing();
             }
 
-            env.write().unwrap().assign(name, Object::Arc(rc!(class)))?;
+            if let Some((mixin, method)) = class_.find_missing_method() {
+                return Err(RuntimeError::new(
+                    name.clone(),
+                    RuntimeErrorType::MissingTraitMethod(mixin.into(), method.into()),
+                )
+                .into());
+            }
+
+            env.write().unwrap().assign(name, class_obj)?;
+
+            if let Some(static_fields) = class.get_static_fields() {
+                let init =
+                    Object::function(SomeFun::Fun(static_fields.clone()), statics_env, false);
+                self.call_object(init, vec![])?;
+            }
             return Ok(Object::Nil);
         }
         Err(RuntimeError::new(name.clone(), RuntimeErrorType::BugEnvironmentNotInit).into())
     }
 
+    fn visit_enum(&mut self, stmt: &Enum) -> Result<Object> {
+        let (name, variants) = stmt.extract();
+        let variants = variants
+            .iter()
+            .map(|(variant, fields)| {
+                let fields = fields
+                    .iter()
+                    .map(|field| field.get_lexeme().to_string())
+                    .collect();
+                (variant.get_lexeme().to_string(), fields)
+            })
+            .collect();
+        let value = object::enumeration::Enum::new(name.get_lexeme().to_string(), variants);
+        match &self.env {
+            None => {
+                Err(RuntimeError::new(name.clone(), RuntimeErrorType::BugEnvironmentNotInit).into())
+            }
+            Some(env) => {
+                env.write()
+                    .unwrap()
+                    .define(name.get_lexeme(), Some(Object::Enum(value)));
+                Ok(Object::Nil)
+            }
+        }
+    }
+
+    fn visit_trait(&mut self, stmt: &Trait<Result<Object>>) -> Result<Object> {
+        let (name, methods, required) = stmt.extract();
+        let methods = self.functions(methods, false);
+        let required = required
+            .iter()
+            .map(|method| method.get_lexeme().to_string())
+            .collect();
+        let value = object::traits::Trait::new(name.get_lexeme().to_string(), methods, required);
+        match &self.env {
+            None => {
+                Err(RuntimeError::new(name.clone(), RuntimeErrorType::BugEnvironmentNotInit).into())
+            }
+            Some(env) => {
+                env.write().unwrap().define(
+                    name.get_lexeme(),
+                    Some(Object::Arc(rc!(Object::Trait(b!(value))))),
+                );
+                Ok(Object::Nil)
+            }
+        }
+    }
+
     fn visit_export(&mut self, class: &Export<Result<Object>>) -> Result<Object> {
         let (_, stmt) = class.extract();
-        self.execute(stmt)?;
+        if let Some(stmt) = stmt {
+            self.execute(stmt)?;
+            return Ok(Object::Nil);
+        }
+        // An export list binds the names exported under another name.
+        for (binding, name) in class.get_names() {
+            if name.get_lexeme() != binding.get_token().get_lexeme() {
+                let value = self.look_up_variable(binding.get_token(), binding)?;
+                self.env
+                    .clone()
+                    .unwrap()
+                    .write()
+                    .unwrap()
+                    .define(name.get_lexeme(), Some(value));
+            }
+        }
         Ok(Object::Nil)
     }
 
-    fn visit_use(&mut self, _stmt: &Use<Result<Object>>) -> Result<Object> {
+    /// Runs a namespaced or selectively imported file in an environment of
+    /// its own and binds its exports; plain `use`s were spliced already.
+    fn visit_use(&mut self, stmt: &Use<Result<Object>>) -> Result<Object> {
+        let (Some(path), Some(module)) = (stmt.get_path(), stmt.get_module()) else {
+            return Ok(Object::Nil);
+        };
+        let mut exports = match self.modules.get(path) {
+            Some(exports) => exports.clone(),
+            None => {
+                let env = rc!(RwLock::new(Environment::new(self.globals.clone())));
+                self.execute_block(module.iter().map(AsRef::as_ref).collect(), env.clone())?;
+
+                let mut exports = HashMap::new();
+                for name in Exporter::exported_names(module) {
+                    let value = env.read().unwrap().get(&name)?;
+                    exports.insert(name.get_lexeme().to_string(), value);
+                }
+                self.modules.insert(path.clone(), exports.clone());
+                exports
+            }
+        };
+
+        let current = self.env.clone().unwrap();
+        match stmt.get_import() {
+            Import::All => {}
+            Import::Module(name) => {
+                let module = Object::Dictionary(rc!(Freezable::new(exports)));
+                object::freeze::seal(&module);
+                current
+                    .write()
+                    .unwrap()
+                    .define(name.get_lexeme(), Some(module));
+            }
+            Import::Names(names) => {
+                for name in names {
+                    let value = exports.remove(name.get_lexeme());
+                    current.write().unwrap().define(name.get_lexeme(), value);
+                }
+            }
+        }
         Ok(Object::Nil)
     }
+
+    fn visit_break(&mut self, _stmt: &Break) -> Result<Object> {
+        Err(InterpreterError::Break)
+    }
+
+    fn visit_continue(&mut self, _stmt: &Continue) -> Result<Object> {
+        Err(InterpreterError::Continue)
+    }
+
+    fn visit_try(&mut self, stmt: &Try<Result<Object>>) -> Result<Object> {
+        let (body, catch, finally) = stmt.extract();
+        let mut res = self.execute_block(
+            body,
+            Arc::new(RwLock::new(Environment::new(self.env.clone()))),
+        );
+
+        if let Err(err) = &res
+            && let Some(value) = err.to_catchable()
+            && let Some((name, handler)) = catch
+        {
+            let mut env = Environment::new(self.env.clone());
+            if let Some(name) = name {
+                env.define(name.get_lexeme(), Some(value));
+            }
+            res = self.execute_block(handler, Arc::new(RwLock::new(env)));
+        }
+
+        // Runs on every way out of the block, including return/break, and
+        // an error raised here replaces whatever was in flight.
+        if let Some(finally) = finally {
+            self.execute_block(
+                finally,
+                Arc::new(RwLock::new(Environment::new(self.env.clone()))),
+            )?;
+        }
+        res
+    }
+
+    fn visit_throw(&mut self, stmt: &Throw<Result<Object>>) -> Result<Object> {
+        let (keyword, value) = stmt.extract();
+        let value = self.evaluate(value)?;
+        Err(RuntimeError::new(keyword.clone(), RuntimeErrorType::Thrown(value)).into())
+    }
 }
@@ -1,7 +1,8 @@
 use crate::interpreter::Interpreter;
 use crate::interpreter::environment::Environment;
-use crate::interpreter::error::{InterpreterError, Result};
+use crate::interpreter::error::{InterpreterError, Result, RuntimeErrorType};
 use crate::interpreter::object::Object;
+use crate::interpreter::object::freeze::Freezable;
 use crate::interpreter::parser::resolver::SomeFun;
 use crate::interpreter::scanner::token::Token;
 use crate::interpreter::scanner::token::token_type::TokenType;
@@ -13,13 +14,49 @@ use std::sync::{Arc, RwLock};
 
 type CallFn = Arc<dyn Fn(&mut Interpreter, Vec<Object>) -> Result<Object> + Send + Sync + 'static>;
 
+/// How many arguments a callable accepts. `max` is `None` for functions
+/// with a trailing `...rest` parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arity {
+    pub min: usize,
+    pub max: Option<usize>,
+}
+
+impl Arity {
+    pub fn exact(n: usize) -> Self {
+        Self {
+            min: n,
+            max: Some(n),
+        }
+    }
+
+    pub fn accepts(&self, n: usize) -> bool {
+        n >= self.min && self.max.is_none_or(|max| n <= max)
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.max.is_none()
+    }
+}
+
+impl Display for Arity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(f, "{}", max),
+            Some(max) => write!(f, "{} to {}", self.min, max),
+            None => write!(f, "at least {}", self.min),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Callable {
     id: u64,
     declaration: Option<Arc<RwLock<SomeFun>>>,
     closure: Option<Arc<RwLock<Environment>>>,
     call: CallFn,
-    arity: Arc<dyn Fn() -> usize + Send + Sync + 'static>,
+    arity: Arc<dyn Fn() -> Arity + Send + Sync + 'static>,
+    params: Arc<Vec<String>>,
     to_string: Arc<dyn Fn() -> String + Send + Sync + 'static>,
     is_init: bool,
 }
@@ -43,22 +80,48 @@ impl Callable {
             }
         };
 
-        let arity = params.len();
+        let arity = Arity {
+            min: params.iter().filter(|param| param.is_required()).count(),
+            max: match params.last() {
+                Some(param) if param.is_variadic() => None,
+                _ => Some(params.len()),
+            },
+        };
+        let names = params
+            .iter()
+            .map(|param| param.get_name().get_lexeme().to_string())
+            .collect();
         let lexeme = name.get_lexeme().to_string();
         Self {
             id,
             declaration,
             closure: closure.clone(),
             is_init,
+            params: rc!(names),
             call: rc!(move |interpreter, args| {
                 let body = body.clone();
-                let mut env = Environment::new(closure.clone());
-                for i in 0..arity {
-                    env.define(params[i].get_lexeme(), Some(args[i].clone()));
+                let closure = Arc::new(RwLock::new(Environment::new(closure.clone())));
+                for (i, param) in params.iter().enumerate() {
+                    let name = param.get_name().get_lexeme();
+                    let value = if param.is_variadic() {
+                        let rest = args.iter().skip(i).cloned().collect();
+                        Object::List(rc!(Freezable::new(rest)))
+                    } else {
+                        match (args.get(i), param.get_default()) {
+                            (Some(arg), _) if !matches!(arg, Object::Void) => arg.clone(),
+                            (_, Some(default)) => {
+                                interpreter.evaluate_in(closure.clone(), default)?
+                            }
+                            _ => {
+                                return Err(
+                                    RuntimeErrorType::MissingArgument(name.to_string()).into()
+                                );
+                            }
+                        }
+                    };
+                    closure.write().unwrap().define(name, Some(value));
                 }
 
-                let closure = Arc::new(RwLock::new(env));
-
                 match interpreter
                     .execute_block(body.iter().map(AsRef::as_ref).collect(), closure.clone())
                 {
@@ -109,7 +172,7 @@ impl Callable {
         declaration: Option<Arc<RwLock<SomeFun>>>,
         closure: Option<Arc<RwLock<Environment>>>,
         call: CallFn,
-        arity: Arc<dyn Fn() -> usize + Send + Sync + 'static>,
+        arity: Arc<dyn Fn() -> Arity + Send + Sync + 'static>,
         to_string: Arc<dyn Fn() -> String + Send + Sync + 'static>,
         is_init: bool,
     ) -> Self {
@@ -119,19 +182,31 @@ impl Callable {
             closure,
             call,
             arity,
+            params: rc!(vec![]),
             to_string,
             is_init,
         }
     }
 
+    /// Names the parameters so that keyword arguments can be matched to
+    /// them. Natives are built without names and only take positional ones.
+    pub fn with_params(mut self, params: Arc<Vec<String>>) -> Self {
+        self.params = params;
+        self
+    }
+
     pub fn _call(&self, interpreter: &mut Interpreter, arguments: Vec<Object>) -> Result<Object> {
         (self.call)(interpreter, arguments)
     }
 
-    pub fn arity(&self) -> usize {
+    pub fn arity(&self) -> Arity {
         (self.arity)()
     }
 
+    pub fn get_params(&self) -> Arc<Vec<String>> {
+        self.params.clone()
+    }
+
     pub fn get_string(&self) -> String {
         (self.to_string)()
     }
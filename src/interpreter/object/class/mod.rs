@@ -1,20 +1,30 @@
+use crate::interpreter::Interpreter;
+use crate::interpreter::error::{Result, RuntimeErrorType};
 use crate::interpreter::object::Object;
-use crate::interpreter::object::callable::Callable;
+use crate::interpreter::object::callable::{Arity, Callable};
 use crate::interpreter::object::instance::Instance;
+use crate::interpreter::object::traits::Trait;
 use crate::rc;
 use crate::utils::next_id;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Class {
     id: u64,
     name: Arc<String>,
     methods: Arc<HashMap<String, Object>>,
     superclass: Option<Object>,
+    /// Included traits in declaration order.
+    mixins: Arc<Vec<Object>>,
+    getters: Arc<HashMap<String, Object>>,
+    setters: Arc<HashMap<String, Object>>,
+    /// Static methods and fields, shared by every copy of the class value.
+    statics: Arc<RwLock<HashMap<String, Object>>>,
+    fields: Option<Object>,
 }
 
 impl Class {
@@ -24,21 +34,126 @@ impl Class {
             name: rc!(name),
             methods: rc!(methods),
             superclass,
+            mixins: Default::default(),
+            getters: Default::default(),
+            setters: Default::default(),
+            statics: Default::default(),
+            fields: None,
         }
     }
 
+    pub fn with_mixins(mut self, mixins: Vec<Object>) -> Self {
+        self.mixins = rc!(mixins);
+        self
+    }
+
+    pub fn with_accessors(
+        mut self,
+        getters: HashMap<String, Object>,
+        setters: HashMap<String, Object>,
+    ) -> Self {
+        self.getters = rc!(getters);
+        self.setters = rc!(setters);
+        self
+    }
+
+    /// Sets the hidden method assigning the declared field initialisers.
+    pub fn with_fields(mut self, fields: Option<Object>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    fn get_superclass(&self) -> Option<&Class> {
+        self.superclass
+            .as_ref()
+            .map(|superclass| match superclass.inner() {
+                Object::Class(class) => class.as_ref(),
+                _ => panic!("Interpreter bug!"),
+            })
+    }
+
+    fn get_mixins(&self) -> impl DoubleEndedIterator<Item = &Trait> {
+        self.mixins.iter().map(|mixin| match mixin.inner() {
+            Object::Trait(mixin) => mixin.as_ref(),
+            _ => panic!("Interpreter bug!"),
+        })
+    }
+
+    /// Resolution order: the class's own methods, then its traits from the
+    /// last included to the first, then the superclass by the same rule.
     pub fn find_method(&self, name: &str) -> Option<Object> {
         if let Some(obj) = self.methods.get(name).cloned() {
+            return Some(obj);
+        }
+        if let Some(obj) = self
+            .get_mixins()
+            .rev()
+            .find_map(|mixin| mixin.find_method(name))
+        {
+            return Some(obj);
+        }
+        self.get_superclass()?.find_method(name)
+    }
+
+    /// The first method required by an included trait that the class
+    /// neither defines, inherits nor gets from another trait.
+    pub fn find_missing_method(&self) -> Option<(&str, &str)> {
+        self.get_mixins().find_map(|mixin| {
+            mixin
+                .get_required()
+                .iter()
+                .find(|method| self.find_method(method).is_none())
+                .map(|method| (mixin.get_name(), method.as_str()))
+        })
+    }
+
+    pub fn find_getter(&self, name: &str) -> Option<Object> {
+        if let Some(obj) = self.getters.get(name).cloned() {
             Some(obj)
-        } else if let Some(superclass) = self.superclass.clone() {
-            match superclass.inner() {
-                Object::Class(class) => class.find_method(name),
-                _ => panic!("Interpreter bug!"),
-            }
         } else {
-            None
+            self.get_superclass()?.find_getter(name)
         }
     }
+
+    pub fn find_setter(&self, name: &str) -> Option<Object> {
+        if let Some(obj) = self.setters.get(name).cloned() {
+            Some(obj)
+        } else {
+            self.get_superclass()?.find_setter(name)
+        }
+    }
+
+    /// Looks up a static member, falling back to the superclass.
+    pub fn get_static(&self, name: &str) -> Result<Object> {
+        if let Some(obj) = self.statics.read().unwrap().get(name) {
+            return Ok(obj.clone());
+        }
+        match self.get_superclass() {
+            Some(superclass) => superclass.get_static(name),
+            None => Err(RuntimeErrorType::UndefinedProperty(name.to_string()).into()),
+        }
+    }
+
+    /// Assigns a static member on this class, shadowing any inherited one.
+    pub fn set_static(&self, name: &str, value: Object) {
+        self.statics
+            .write()
+            .unwrap()
+            .insert(name.to_string(), value);
+    }
+
+    /// Runs the field initialisers of the whole hierarchy on `instance`,
+    /// superclass first so subclasses can override inherited defaults.
+    fn init_fields(&self, interpreter: &mut Interpreter, instance: &Instance) -> Result<()> {
+        if let Some(superclass) = self.get_superclass() {
+            superclass.init_fields(interpreter, instance)?;
+        }
+        if let Some(fields) = &self.fields {
+            let fields = fields.bind(instance.clone())?;
+            interpreter.call_object(fields, vec![])?;
+        }
+        Ok(())
+    }
 }
 
 impl Display for Class {
@@ -47,18 +162,31 @@ impl Display for Class {
     }
 }
 
+impl PartialEq for Class {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Class {}
+
 impl From<Class> for Callable {
     fn from(value: Class) -> Self {
         let name = value.name.clone();
         let value_call = value.clone();
         let value_arity = value.clone();
         let is_init = value.methods.get("init").is_some();
+        let params = match value.find_method("init") {
+            Some(Object::Callable(initializer)) => initializer.get_params(),
+            _ => rc!(vec![]),
+        };
         Callable::build(
             value.id,
             None,
             None,
             rc!(move |interpreter, args| {
                 let instance = Instance::new(value_call.clone());
+                value_call.init_fields(interpreter, &instance)?;
 
                 if let Some(initializer) = value_call.find_method("init") {
                     match initializer.bind(instance.clone())? {
@@ -78,12 +206,13 @@ impl From<Class> for Callable {
                         _ => panic!("Interpreter bug!"),
                     }
                 } else {
-                    0
+                    Arity::exact(0)
                 }
             ),
             rc!(move || name.to_string()),
             is_init,
         )
+        .with_params(params)
     }
 }
 
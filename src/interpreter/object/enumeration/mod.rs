@@ -0,0 +1,220 @@
+use crate::interpreter::error::{Result, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::callable::{Arity, Callable};
+use crate::rc;
+use crate::utils::next_id;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// A declared enum: its name and each variant with the names of its
+/// payload fields. Variants without fields are plain values, the others
+/// are constructors.
+#[derive(Debug, Clone)]
+pub struct Enum {
+    id: u64,
+    name: Arc<String>,
+    variants: Arc<Vec<(String, Vec<String>)>>,
+}
+
+/// One value of an enum, e.g. `Shape.Circle(2)` or `Primitive.Triangles`.
+#[derive(Debug, Clone)]
+pub struct Variant {
+    enum_id: u64,
+    enum_name: Arc<String>,
+    name: Arc<String>,
+    fields: Arc<Vec<String>>,
+    values: Arc<Vec<Object>>,
+}
+
+impl Enum {
+    pub fn new(name: String, variants: Vec<(String, Vec<String>)>) -> Self {
+        Self {
+            id: next_id(),
+            name: rc!(name),
+            variants: rc!(variants),
+        }
+    }
+
+    fn unit(name: &str, variants: &[&str]) -> Self {
+        Self::new(
+            name.into(),
+            variants
+                .iter()
+                .map(|variant| (variant.to_string(), vec![]))
+                .collect(),
+        )
+    }
+
+    /// Enums the engine understands wherever it used to take strings:
+    /// `Primitive` for `primitive`, `Uniform` for a uniform's `type` and
+    /// `Key` for `regKeyEvent`, along with `Iteration`, whose `Done` ends a
+    /// user-defined iterator.
+    pub fn engine() -> Vec<Enum> {
+        let letters: Vec<String> = ('A'..='Z').map(String::from).collect();
+        let digits: Vec<String> = (0..10).map(|i| format!("Digit{}", i)).collect();
+        let keys: Vec<&str> = letters
+            .iter()
+            .chain(&digits)
+            .map(String::as_str)
+            .chain(["Space"])
+            .collect();
+        vec![
+            Enum::unit(
+                "Primitive",
+                &[
+                    "Points",
+                    "Lines",
+                    "LineStrip",
+                    "LineLoop",
+                    "Triangles",
+                    "TriangleStrip",
+                    "TriangleFan",
+                ],
+            ),
+            Enum::unit(
+                "Uniform",
+                &["Float", "Vec2", "Vec3", "Vec4", "Mat3", "Mat4", "Sampler2D"],
+            ),
+            Enum::unit("Key", &keys),
+            Enum::unit("Iteration", &["Done"]),
+        ]
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn variant_names(&self) -> impl Iterator<Item = &str> {
+        self.variants.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// `Enum.Variant`: the value of a unit variant or the constructor of a
+    /// variant with fields.
+    pub fn get(&self, name: &str) -> Result<Object> {
+        let Some((name, fields)) = self.variants.iter().find(|(variant, _)| variant == name) else {
+            return Err(RuntimeErrorType::UndefinedVariant(
+                self.name.to_string(),
+                name.to_string(),
+            )
+            .into());
+        };
+
+        let variant = Variant {
+            enum_id: self.id,
+            enum_name: self.name.clone(),
+            name: rc!(name.clone()),
+            fields: rc!(fields.clone()),
+            values: rc!(vec![]),
+        };
+        if fields.is_empty() {
+            return Ok(Object::Variant(variant));
+        }
+
+        let arity = Arity::exact(fields.len());
+        let to_string = variant.to_string();
+        Ok(Object::Callable(
+            Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(move |_, args| {
+                    Ok(Object::Variant(Variant {
+                        values: rc!(args),
+                        ..variant.clone()
+                    }))
+                }),
+                rc!(move || arity),
+                rc!(move || to_string.clone()),
+                false,
+            )
+            .with_params(rc!(fields.clone())),
+        ))
+    }
+}
+
+impl Variant {
+    pub fn get_enum_name(&self) -> &str {
+        &self.enum_name
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_values(&self) -> &[Object] {
+        &self.values
+    }
+
+    /// A payload value, by field name or by position.
+    pub fn get_field(&self, key: &Object) -> Result<Object> {
+        let index = match key.inner() {
+            Object::String(field) => self.fields.iter().position(|name| name == field),
+            Object::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as usize),
+            _ => None,
+        };
+        index
+            .and_then(|i| self.values.get(i).cloned())
+            .ok_or_else(|| RuntimeErrorType::UndefinedProperty(key.to_string()).into())
+    }
+
+    /// The legacy string a predefined engine enum value stands for, e.g.
+    /// `"triangleStrip"` for `Primitive.TriangleStrip`, `"w"` for `Key.W`.
+    pub fn to_engine_string(&self, enum_name: &str) -> Option<String> {
+        if self.enum_name.as_str() != enum_name {
+            return None;
+        }
+        if enum_name == "Key" {
+            return Some(match self.name.as_str() {
+                "Space" => " ".into(),
+                name => name.trim_start_matches("Digit").to_lowercase(),
+            });
+        }
+        let mut chars = self.name.chars();
+        let first = chars.next()?.to_lowercase();
+        Some(first.chain(chars).collect())
+    }
+}
+
+impl Display for Enum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Display for Variant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.enum_name, self.name)?;
+        if !self.values.is_empty() {
+            let values: Vec<_> = self.values.iter().map(ToString::to_string).collect();
+            write!(f, "({})", values.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for Enum {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl PartialEq for Variant {
+    fn eq(&self, other: &Self) -> bool {
+        self.enum_id == other.enum_id && self.name == other.name && self.values == other.values
+    }
+}
+
+impl Hash for Enum {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Hash for Variant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.enum_id.hash(state);
+        self.name.hash(state);
+        self.values.hash(state);
+    }
+}
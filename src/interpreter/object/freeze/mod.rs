@@ -0,0 +1,79 @@
+use crate::interpreter::object::Object;
+use std::collections::HashSet;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Shared storage of a list or dictionary, which `freeze` can make
+/// immutable. Derefs to the lock around the value.
+#[derive(Debug, Default)]
+pub struct Freezable<T> {
+    value: RwLock<T>,
+    frozen: AtomicBool,
+}
+
+impl<T> Freezable<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value: RwLock::new(value),
+            frozen: AtomicBool::new(false),
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.load(Ordering::Relaxed)
+    }
+
+    fn freeze(&self) {
+        self.frozen.store(true, Ordering::Relaxed);
+    }
+}
+
+impl<T> Deref for Freezable<T> {
+    type Target = RwLock<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Makes `obj` and every list or dictionary nested in it immutable.
+/// Other values are left untouched.
+pub fn freeze(obj: &Object) {
+    freeze_nested(obj, &mut HashSet::new());
+}
+
+fn freeze_nested(obj: &Object, visited: &mut HashSet<*const ()>) {
+    let (key, children): (*const (), Vec<Object>) = match obj.inner() {
+        Object::List(list) => (Arc::as_ptr(list) as _, list.read().unwrap().clone()),
+        Object::Dictionary(map) => (
+            Arc::as_ptr(map) as _,
+            map.read().unwrap().values().cloned().collect(),
+        ),
+        _ => return,
+    };
+    if !visited.insert(key) {
+        return;
+    }
+    seal(obj);
+    for child in &children {
+        freeze_nested(child, visited);
+    }
+}
+
+/// Makes a list or dictionary immutable without touching the values in it.
+pub fn seal(obj: &Object) {
+    match obj.inner() {
+        Object::List(list) => list.freeze(),
+        Object::Dictionary(map) => map.freeze(),
+        _ => {}
+    }
+}
+
+pub fn is_frozen(obj: &Object) -> bool {
+    match obj.inner() {
+        Object::List(list) => list.is_frozen(),
+        Object::Dictionary(map) => map.is_frozen(),
+        _ => false,
+    }
+}
@@ -1,5 +1,5 @@
-use crate::interpreter::error::Result;
-use crate::interpreter::error::{RuntimeError, RuntimeErrorType};
+use crate::interpreter::Interpreter;
+use crate::interpreter::error::{Result, RuntimeErrorType};
 use crate::interpreter::object::Object;
 use crate::interpreter::object::class::Class;
 use crate::interpreter::scanner::token::Token;
@@ -22,27 +22,78 @@ impl Instance {
         }
     }
 
-    pub fn get(&self, name: &Token) -> Result<Object> {
-        if let Some(obj) = self.fields.read().unwrap().get(name.get_lexeme()) {
+    /// Reads a property, calling its getter if the class declares one.
+    pub fn get(&self, interpreter: &mut Interpreter, name: &Token) -> Result<Object> {
+        let res = self.get_property(interpreter, name.get_lexeme());
+        Interpreter::handle_runtime_error(name.clone(), res)
+    }
+
+    pub fn get_property(&self, interpreter: &mut Interpreter, name: &str) -> Result<Object> {
+        match self.class.find_getter(name) {
+            Some(getter) => interpreter.call_object(getter.bind(self.clone())?, vec![]),
+            None => self.get_field(name),
+        }
+    }
+
+    /// Reads a property like `get_property`, or `None` if the instance has
+    /// no field, method or getter called `name`.
+    pub fn find_property(
+        &self,
+        interpreter: &mut Interpreter,
+        name: &str,
+    ) -> Result<Option<Object>> {
+        match self.class.find_getter(name) {
+            Some(getter) => interpreter
+                .call_object(getter.bind(self.clone())?, vec![])
+                .map(Some),
+            None => Ok(self.get_field(name).ok()),
+        }
+    }
+
+    pub fn get_field(&self, name: &str) -> Result<Object> {
+        if let Some(obj) = self.fields.read().unwrap().get(name) {
             return Ok(obj.clone());
         }
 
-        if let Some(method) = self.class.find_method(name.get_lexeme()) {
+        if let Some(method) = self.class.find_method(name) {
             return method.bind(self.clone());
         }
 
-        Err(RuntimeError::new(
-            name.clone(),
-            RuntimeErrorType::UndefinedProperty(name.get_lexeme().to_string()),
-        )
-        .into())
+        Err(RuntimeErrorType::UndefinedProperty(name.to_string()).into())
     }
 
-    pub fn set(&self, name: &Token, value: Object) {
-        self.fields
-            .write()
-            .unwrap()
-            .insert(name.get_lexeme().to_string(), value);
+    /// A method of the instance's class bound to it, ignoring fields.
+    pub fn get_method(&self, name: &str) -> Result<Option<Object>> {
+        self.class
+            .find_method(name)
+            .map(|method| method.bind(self.clone()))
+            .transpose()
+    }
+
+    /// Writes a property, calling its setter if the class declares one.
+    pub fn set(&self, interpreter: &mut Interpreter, name: &Token, value: Object) -> Result<()> {
+        let res = self.set_property(interpreter, name.get_lexeme(), value);
+        Interpreter::handle_runtime_error(name.clone(), res)
+    }
+
+    pub fn set_property(
+        &self,
+        interpreter: &mut Interpreter,
+        name: &str,
+        value: Object,
+    ) -> Result<()> {
+        if let Some(setter) = self.class.find_setter(name) {
+            interpreter.call_object(setter.bind(self.clone())?, vec![value])?;
+        } else if self.class.find_getter(name).is_some() {
+            return Err(RuntimeErrorType::ReadOnlyProperty(name.to_string()).into());
+        } else {
+            self.set_field(name, value);
+        }
+        Ok(())
+    }
+
+    pub fn set_field(&self, name: &str, value: Object) {
+        self.fields.write().unwrap().insert(name.to_string(), value);
     }
 }
 
@@ -52,14 +103,11 @@ impl Display for Instance {
     }
 }
 
+/// Instances are equal only to themselves; a class compares them by value
+/// by defining `eq`.
 impl PartialEq for Instance {
     fn eq(&self, other: &Self) -> bool {
-        self.class == other.class
-            && self
-                .fields
-                .read()
-                .unwrap()
-                .eq(&other.fields.read().unwrap())
+        Arc::ptr_eq(&self.fields, &other.fields)
     }
 }
 
@@ -67,11 +115,6 @@ impl Eq for Instance {}
 
 impl Hash for Instance {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.class.hash(state);
-        let fields = self.fields.read().unwrap();
-        for (k, v) in fields.iter() {
-            k.hash(state);
-            v.hash(state);
-        }
+        Arc::as_ptr(&self.fields).hash(state);
     }
 }
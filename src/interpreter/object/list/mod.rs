@@ -0,0 +1,256 @@
+use crate::interpreter::Interpreter;
+use crate::interpreter::error::{Result, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::callable::{Arity, Callable};
+use crate::interpreter::object::freeze;
+use crate::interpreter::object::freeze::Freezable;
+use crate::rc;
+use crate::utils::next_id;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+type List = Arc<Freezable<Vec<Object>>>;
+
+/// Names of the methods every list has.
+const METHODS: [&str; 11] = [
+    "push", "pop", "insert", "remove", "extend", "len", "slice", "sort", "reverse", "contains",
+    "indexOf",
+];
+
+/// `list.name`: one of the list methods bound to `list`.
+pub fn method(list: &Object, name: &str) -> Result<Object> {
+    let Some(name) = METHODS.iter().copied().find(|method| *method == name) else {
+        return Err(RuntimeErrorType::UndefinedProperty(name.to_string()).into());
+    };
+    let arity = match name {
+        "push" => Arity { min: 1, max: None },
+        "pop" | "len" | "reverse" => Arity::exact(0),
+        "insert" => Arity::exact(2),
+        "slice" => Arity {
+            min: 1,
+            max: Some(2),
+        },
+        "sort" => Arity {
+            min: 0,
+            max: Some(1),
+        },
+        _ => Arity::exact(1),
+    };
+    let list = list.clone();
+    Ok(Object::Callable(Callable::build(
+        next_id(),
+        None,
+        None,
+        rc!(move |interpreter, args| call(interpreter, &list, name, args)),
+        rc!(move || arity),
+        rc!(move || name.into()),
+        false,
+    )))
+}
+
+fn call(
+    interpreter: &mut Interpreter,
+    obj: &Object,
+    name: &str,
+    args: Vec<Object>,
+) -> Result<Object> {
+    let Object::List(list) = obj.inner() else {
+        panic!("Interpreter bug!");
+    };
+    let mutates = matches!(
+        name,
+        "push" | "pop" | "insert" | "remove" | "extend" | "sort" | "reverse"
+    );
+    if mutates && freeze::is_frozen(obj) {
+        return Err(RuntimeErrorType::FrozenValue(obj.get_type()).into());
+    }
+
+    match name {
+        "push" => list.write().unwrap().extend(args),
+        "pop" => return Ok(list.write().unwrap().pop().unwrap_or(Object::Nil)),
+        "insert" => {
+            let i = args[0].as_index()?;
+            let mut list = list.write().unwrap();
+            if i > list.len() {
+                return Err(RuntimeErrorType::IndexOutOfBounds(i, list.len()).into());
+            }
+            list.insert(i, args[1].clone());
+        }
+        "remove" => {
+            let i = args[0].as_index()?;
+            let mut list = list.write().unwrap();
+            if i >= list.len() {
+                return Err(RuntimeErrorType::IndexOutOfBounds(i, list.len()).into());
+            }
+            return Ok(list.remove(i));
+        }
+        "extend" => {
+            let values: Vec<_> = args[0].entries()?.map(|(_, value)| value).collect();
+            list.write().unwrap().extend(values);
+        }
+        "len" => return Ok(Object::Number(list.read().unwrap().len() as f64)),
+        "slice" => return slice(list, &args),
+        "sort" => sort(interpreter, list, args.first())?,
+        "reverse" => list.write().unwrap().reverse(),
+        "contains" => {
+            return Ok(Object::Bool(
+                index_of(interpreter, list, &args[0])?.is_some(),
+            ));
+        }
+        "indexOf" => {
+            return Ok(index_of(interpreter, list, &args[0])?
+                .map_or(Object::Nil, |i| Object::Number(i as f64)));
+        }
+        _ => panic!("Interpreter bug!"),
+    }
+    Ok(Object::Nil)
+}
+
+/// `list.slice(start, end)`: a new list with the elements in `start..end`,
+/// both clamped to the length; `end` defaults to the length.
+fn slice(list: &List, args: &[Object]) -> Result<Object> {
+    let list = list.read().unwrap();
+    let start = args[0].as_index()?.min(list.len());
+    let end = match args.get(1) {
+        Some(end) => end.as_index()?.clamp(start, list.len()),
+        None => list.len(),
+    };
+    Ok(Object::List(rc!(Freezable::new(list[start..end].to_vec()))))
+}
+
+/// Sorts in place, by the natural order of numbers or of strings, or by
+/// `compare(a, b)`, which returns a negative number when `a` goes first.
+/// The list is sorted as a copy so the comparator may read it.
+fn sort(interpreter: &mut Interpreter, list: &List, compare: Option<&Object>) -> Result<()> {
+    let values = list.read().unwrap().clone();
+    let values = match compare {
+        Some(compare) => merge_sort(values, &mut |a, b| match interpreter
+            .call_object(compare.clone(), vec![a.clone(), b.clone()])?
+        {
+            Object::Number(n) if n < 0.0 => Ok(Ordering::Less),
+            Object::Number(n) if n > 0.0 => Ok(Ordering::Greater),
+            Object::Number(_) => Ok(Ordering::Equal),
+            _ => Err(RuntimeErrorType::MustBeANumber.into()),
+        })?,
+        None => merge_sort(values, &mut natural_order)?,
+    };
+    *list.write().unwrap() = values;
+    Ok(())
+}
+
+/// Numbers by value, NaN last, and strings alphabetically; anything else
+/// can't be sorted without a comparator.
+fn natural_order(a: &Object, b: &Object) -> Result<Ordering> {
+    match (a.inner(), b.inner()) {
+        (Object::Number(a), Object::Number(b)) => Ok(a
+            .partial_cmp(b)
+            .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))),
+        (Object::String(a), Object::String(b)) => Ok(a.cmp(b)),
+        _ => Err(RuntimeErrorType::CannotCompareTypes(a.get_type(), b.get_type()).into()),
+    }
+}
+
+/// A stable merge sort stopping at the first error of `compare`. Unlike
+/// `slice::sort_by` it accepts a comparator that isn't a total order.
+fn merge_sort(
+    mut values: Vec<Object>,
+    compare: &mut impl FnMut(&Object, &Object) -> Result<Ordering>,
+) -> Result<Vec<Object>> {
+    if values.len() <= 1 {
+        return Ok(values);
+    }
+    let right = values.split_off(values.len() / 2);
+    let mut left = merge_sort(values, compare)?.into_iter().peekable();
+    let mut right = merge_sort(right, compare)?.into_iter().peekable();
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+        let next = match compare(b, a)? {
+            Ordering::Less => right.next(),
+            _ => left.next(),
+        };
+        merged.extend(next);
+    }
+    merged.extend(left);
+    merged.extend(right);
+    Ok(merged)
+}
+
+fn index_of(interpreter: &mut Interpreter, list: &List, value: &Object) -> Result<Option<usize>> {
+    let values = list.read().unwrap().clone();
+    for (i, elm) in values.iter().enumerate() {
+        if interpreter.objects_equal(elm, value)? {
+            return Ok(Some(i));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::error::InterpreterError;
+
+    fn numbers(values: &[f64]) -> Vec<Object> {
+        values.iter().map(|n| Object::Number(*n)).collect()
+    }
+
+    #[test]
+    fn natural_order_puts_nan_last() {
+        let sorted = merge_sort(numbers(&[3.0, f64::NAN, -1.0, 2.0]), &mut natural_order).unwrap();
+        assert_eq!(sorted[..3], numbers(&[-1.0, 2.0, 3.0]));
+        assert!(matches!(sorted[3], Object::Number(n) if n.is_nan()));
+    }
+
+    #[test]
+    fn equal_values_keep_their_order() {
+        let pairs: Vec<_> = [(1.0, "a"), (0.0, "b"), (1.0, "c"), (0.0, "d")]
+            .iter()
+            .map(|(key, name)| {
+                Object::List(rc!(Freezable::new(vec![
+                    Object::Number(*key),
+                    Object::String(name.to_string()),
+                ])))
+            })
+            .collect();
+        let key = |obj: &Object| obj.get_field(Object::Number(0.0)).unwrap();
+        let sorted = merge_sort(pairs, &mut |a, b| natural_order(&key(a), &key(b))).unwrap();
+        let names: Vec<_> = sorted
+            .iter()
+            .map(|pair| pair.get_field(Object::Number(1.0)).unwrap().to_string())
+            .collect();
+        assert_eq!(names, ["b", "d", "a", "c"]);
+    }
+
+    #[test]
+    fn failing_comparator_stops_at_the_first_error() {
+        let mut calls = 0;
+        let res = merge_sort(numbers(&[4.0, 3.0, 2.0, 1.0]), &mut |_, _| {
+            calls += 1;
+            Err(RuntimeErrorType::MustBeANumber.into())
+        });
+        assert!(res.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn mixed_types_cannot_be_compared() {
+        let values = vec![Object::Number(1.0), Object::String("a".into())];
+        let err = merge_sort(values, &mut natural_order).unwrap_err();
+        assert!(matches!(
+            err,
+            InterpreterError::RuntimeErrorType(RuntimeErrorType::CannotCompareTypes(..))
+        ));
+    }
+
+    #[test]
+    fn inconsistent_comparator_still_returns_every_value() {
+        let sorted = merge_sort(numbers(&[1.0, 2.0, 3.0, 4.0, 5.0]), &mut |_, _| {
+            Ok(Ordering::Less)
+        })
+        .unwrap();
+        let mut values: Vec<_> = sorted.iter().map(ToString::to_string).collect();
+        values.sort();
+        assert_eq!(values, ["1", "2", "3", "4", "5"]);
+    }
+}
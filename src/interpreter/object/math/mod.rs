@@ -0,0 +1,451 @@
+use crate::interpreter::error::{Result, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::callable::Arity;
+use cgmath::{
+    Deg, ElementWise, EuclideanSpace, InnerSpace, Matrix, Matrix3, Matrix4, Point3, Quaternion,
+    Rad, Rotation, Rotation3, SquareMatrix, Vector2, Vector3, Vector4, perspective,
+};
+use std::fmt::{Display, Formatter};
+use std::ops::Neg;
+
+type MathFn = fn(&[Object]) -> Result<Object>;
+
+/// Built-in vector, matrix and quaternion values. They are plain values:
+/// operators and natives always return new ones. Matrices are column-major,
+/// like the nested lists scripts used to build them from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Math {
+    Vec2(Vector2<f64>),
+    Vec3(Vector3<f64>),
+    Vec4(Vector4<f64>),
+    Mat3(Matrix3<f64>),
+    Mat4(Matrix4<f64>),
+    Quat(Quaternion<f64>),
+}
+
+const SWIZZLE_SETS: [&str; 2] = ["xyzw", "rgba"];
+
+impl Math {
+    pub fn get_type(&self) -> &'static str {
+        match self {
+            Math::Vec2(_) => "vec2",
+            Math::Vec3(_) => "vec3",
+            Math::Vec4(_) => "vec4",
+            Math::Mat3(_) => "mat3",
+            Math::Mat4(_) => "mat4",
+            Math::Quat(_) => "quat",
+        }
+    }
+
+    /// Components in storage order: columns one after another for matrices,
+    /// `x, y, z, w` for quaternions.
+    pub fn components(&self) -> Vec<f64> {
+        match self {
+            Math::Vec2(v) => AsRef::<[f64; 2]>::as_ref(v).to_vec(),
+            Math::Vec3(v) => AsRef::<[f64; 3]>::as_ref(v).to_vec(),
+            Math::Vec4(v) => AsRef::<[f64; 4]>::as_ref(v).to_vec(),
+            Math::Mat3(m) => AsRef::<[f64; 9]>::as_ref(m).to_vec(),
+            Math::Mat4(m) => AsRef::<[f64; 16]>::as_ref(m).to_vec(),
+            Math::Quat(q) => vec![q.v.x, q.v.y, q.v.z, q.s],
+        }
+    }
+
+    fn vector(components: &[f64]) -> Option<Math> {
+        match *components {
+            [x, y] => Some(Math::Vec2(Vector2::new(x, y))),
+            [x, y, z] => Some(Math::Vec3(Vector3::new(x, y, z))),
+            [x, y, z, w] => Some(Math::Vec4(Vector4::new(x, y, z, w))),
+            _ => None,
+        }
+    }
+
+    fn is_vector(&self) -> bool {
+        matches!(self, Math::Vec2(_) | Math::Vec3(_) | Math::Vec4(_))
+    }
+
+    /// `v.x`, `v.zyx`, `c.rgb`: one name gives a number, several a vector.
+    pub fn get(&self, name: &str) -> Result<Object> {
+        let undefined = || RuntimeErrorType::UndefinedProperty(name.to_string()).into();
+        if !self.is_vector() && !matches!(self, Math::Quat(_)) {
+            return Err(undefined());
+        }
+        let components = self.components();
+        let selected = SWIZZLE_SETS
+            .iter()
+            .find_map(|set| {
+                name.chars()
+                    .map(|c| set.find(c).and_then(|i| components.get(i).copied()))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or_else(undefined)?;
+        match selected.as_slice() {
+            [value] => Ok(Object::Number(*value)),
+            values => Math::vector(values).map(Object::Math).ok_or_else(undefined),
+        }
+    }
+
+    /// `v[i]` is a component, `m[i]` a column.
+    pub fn index(&self, i: usize) -> Result<Object> {
+        let components = self.components();
+        let (size, stride) = match self {
+            Math::Mat3(_) => (3, 3),
+            Math::Mat4(_) => (4, 4),
+            _ => (components.len(), 1),
+        };
+        if i >= size {
+            return Err(RuntimeErrorType::IndexOutOfBounds(i, size).into());
+        }
+        Ok(match stride {
+            1 => Object::Number(components[i]),
+            _ => Object::Math(Math::vector(&components[i * stride..(i + 1) * stride]).unwrap()),
+        })
+    }
+
+    /// Builds a value of type `ty` from constructor arguments, GLSL style:
+    /// numbers, vectors and lists of numbers are flattened into components.
+    pub fn construct(ty: &str, args: &[Object]) -> Result<Object> {
+        if let [Object::Math(value)] = args
+            && let Some(converted) = value.convert(ty)
+        {
+            return Ok(Object::Math(converted));
+        }
+
+        let mut components = vec![];
+        for arg in args {
+            flatten(arg, &mut components)?;
+        }
+        let size = match ty {
+            "vec2" => 2,
+            "vec3" => 3,
+            "vec4" | "quat" => 4,
+            "mat3" => 9,
+            _ => 16,
+        };
+
+        let value = match (ty, components.as_slice()) {
+            ("mat3", []) => Math::Mat3(Matrix3::identity()),
+            ("mat4", []) => Math::Mat4(Matrix4::identity()),
+            ("mat3", [k]) => Math::Mat3(Matrix3::from_value(*k)),
+            ("mat4", [k]) => Math::Mat4(Matrix4::from_value(*k)),
+            ("vec2" | "vec3" | "vec4", [k]) => Math::vector(&vec![*k; size]).unwrap(),
+            ("quat", [x, y, z, angle]) if matches!(args, [Object::Math(Math::Vec3(_)), _]) => {
+                Math::Quat(Quaternion::from_axis_angle(
+                    Vector3::new(*x, *y, *z).normalize(),
+                    Rad(*angle),
+                ))
+            }
+            (_, c) if c.len() == size => match ty {
+                "mat3" => Math::Mat3(*<&Matrix3<f64>>::from(<&[f64; 9]>::try_from(c).unwrap())),
+                "mat4" => Math::Mat4(*<&Matrix4<f64>>::from(<&[f64; 16]>::try_from(c).unwrap())),
+                "quat" => Math::Quat(Quaternion::new(c[3], c[0], c[1], c[2])),
+                _ => Math::vector(c).unwrap(),
+            },
+            (_, c) => {
+                return Err(
+                    RuntimeErrorType::InvalidComponents(ty.to_string(), size, c.len()).into(),
+                );
+            }
+        };
+        Ok(Object::Math(value))
+    }
+
+    /// Conversions that are not a plain reshuffle of components.
+    fn convert(&self, ty: &str) -> Option<Math> {
+        Some(match (ty, self) {
+            ("mat3", Math::Mat4(m)) => Math::Mat3(Matrix3::from_cols(
+                m.x.truncate(),
+                m.y.truncate(),
+                m.z.truncate(),
+            )),
+            ("mat4", Math::Mat3(m)) => Math::Mat4(Matrix4::from(*m)),
+            ("mat3", Math::Quat(q)) => Math::Mat3(Matrix3::from(*q)),
+            ("mat4", Math::Quat(q)) => Math::Mat4(Matrix4::from(*q)),
+            ("quat", Math::Mat3(m)) => Math::Quat(Quaternion::from(*m)),
+            _ => return None,
+        })
+    }
+
+    fn scale(self, k: f64) -> Math {
+        match self {
+            Math::Vec2(v) => Math::Vec2(v * k),
+            Math::Vec3(v) => Math::Vec3(v * k),
+            Math::Vec4(v) => Math::Vec4(v * k),
+            Math::Mat3(m) => Math::Mat3(m * k),
+            Math::Mat4(m) => Math::Mat4(m * k),
+            Math::Quat(q) => Math::Quat(q * k),
+        }
+    }
+
+    /// `None` when the operands can't be added.
+    pub fn add(lhs: &Object, rhs: &Object) -> Option<Object> {
+        let (Object::Math(a), Object::Math(b)) = (lhs, rhs) else {
+            return None;
+        };
+        Some(Object::Math(match (*a, *b) {
+            (Math::Vec2(a), Math::Vec2(b)) => Math::Vec2(a + b),
+            (Math::Vec3(a), Math::Vec3(b)) => Math::Vec3(a + b),
+            (Math::Vec4(a), Math::Vec4(b)) => Math::Vec4(a + b),
+            (Math::Mat3(a), Math::Mat3(b)) => Math::Mat3(a + b),
+            (Math::Mat4(a), Math::Mat4(b)) => Math::Mat4(a + b),
+            (Math::Quat(a), Math::Quat(b)) => Math::Quat(a + b),
+            _ => return None,
+        }))
+    }
+
+    pub fn sub(lhs: &Object, rhs: &Object) -> Option<Object> {
+        let Object::Math(b) = rhs else {
+            return None;
+        };
+        Math::add(lhs, &Object::Math(-*b))
+    }
+
+    /// Scaling by a number, component-wise products of vectors, matrix
+    /// products, quaternion products and rotating a `vec3` by a quaternion.
+    pub fn mul(lhs: &Object, rhs: &Object) -> Option<Object> {
+        let value = match (lhs, rhs) {
+            (Object::Math(a), Object::Number(k)) | (Object::Number(k), Object::Math(a)) => {
+                a.scale(*k)
+            }
+            (Object::Math(a), Object::Math(b)) => match (*a, *b) {
+                (Math::Vec2(a), Math::Vec2(b)) => Math::Vec2(a.mul_element_wise(b)),
+                (Math::Vec3(a), Math::Vec3(b)) => Math::Vec3(a.mul_element_wise(b)),
+                (Math::Vec4(a), Math::Vec4(b)) => Math::Vec4(a.mul_element_wise(b)),
+                (Math::Mat3(a), Math::Vec3(b)) => Math::Vec3(a * b),
+                (Math::Mat4(a), Math::Vec4(b)) => Math::Vec4(a * b),
+                (Math::Mat3(a), Math::Mat3(b)) => Math::Mat3(a * b),
+                (Math::Mat4(a), Math::Mat4(b)) => Math::Mat4(a * b),
+                (Math::Quat(a), Math::Quat(b)) => Math::Quat(a * b),
+                (Math::Quat(a), Math::Vec3(b)) => Math::Vec3(a.rotate_vector(b)),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(Object::Math(value))
+    }
+
+    pub fn div(lhs: &Object, rhs: &Object) -> Option<Object> {
+        let value = match (lhs, rhs) {
+            (Object::Math(a), Object::Number(k)) => a.scale(1.0 / k),
+            (Object::Math(a), Object::Math(b)) => match (*a, *b) {
+                (Math::Vec2(a), Math::Vec2(b)) => Math::Vec2(a.div_element_wise(b)),
+                (Math::Vec3(a), Math::Vec3(b)) => Math::Vec3(a.div_element_wise(b)),
+                (Math::Vec4(a), Math::Vec4(b)) => Math::Vec4(a.div_element_wise(b)),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(Object::Math(value))
+    }
+
+    /// Natives working on math values: name, arity and implementation.
+    pub fn natives() -> Vec<(&'static str, Arity, MathFn)> {
+        let any = |max| Arity { min: 0, max };
+        vec![
+            ("vec2", any(Some(2)), |args| Math::construct("vec2", args)),
+            ("vec3", any(Some(3)), |args| Math::construct("vec3", args)),
+            ("vec4", any(Some(4)), |args| Math::construct("vec4", args)),
+            ("mat3", any(Some(9)), |args| Math::construct("mat3", args)),
+            ("mat4", any(Some(16)), |args| Math::construct("mat4", args)),
+            ("quat", any(Some(4)), |args| Math::construct("quat", args)),
+            ("dot", Arity::exact(2), dot),
+            ("cross", Arity::exact(2), cross),
+            ("length", Arity::exact(1), length),
+            ("normalize", Arity::exact(1), normalize),
+            ("inverse", Arity::exact(1), inverse),
+            ("transpose", Arity::exact(1), transpose),
+            ("lookAt", Arity::exact(3), look_at),
+            ("perspective", Arity::exact(4), perspective_matrix),
+        ]
+    }
+}
+
+fn flatten(obj: &Object, components: &mut Vec<f64>) -> Result<()> {
+    match obj.inner() {
+        Object::Number(n) => components.push(*n),
+        Object::Math(value) => components.extend(value.components()),
+        Object::List(list) => {
+            for item in list.read().unwrap().iter() {
+                flatten(item, components)?;
+            }
+        }
+        obj => {
+            return Err(RuntimeErrorType::InvalidArgument(
+                "number or vector".into(),
+                obj.get_type(),
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+fn math_arg(obj: &Object, expected: &str) -> Result<Math> {
+    match obj.inner() {
+        Object::Math(value) => Ok(*value),
+        obj => Err(RuntimeErrorType::InvalidArgument(expected.into(), obj.get_type()).into()),
+    }
+}
+
+fn number_arg(obj: &Object) -> Result<f64> {
+    match obj.inner() {
+        Object::Number(n) => Ok(*n),
+        obj => Err(RuntimeErrorType::InvalidArgument("number".into(), obj.get_type()).into()),
+    }
+}
+
+/// A `vec3`, or anything `vec3()` accepts such as a list of three numbers.
+fn vec3_arg(obj: &Object) -> Result<Vector3<f64>> {
+    match Math::construct("vec3", std::slice::from_ref(obj))? {
+        Object::Math(Math::Vec3(v)) => Ok(v),
+        _ => unreachable!(),
+    }
+}
+
+fn dot(args: &[Object]) -> Result<Object> {
+    let (a, b) = (math_arg(&args[0], "vector")?, math_arg(&args[1], "vector")?);
+    Ok(Object::Number(match (a, b) {
+        (Math::Vec2(a), Math::Vec2(b)) => a.dot(b),
+        (Math::Vec3(a), Math::Vec3(b)) => a.dot(b),
+        (Math::Vec4(a), Math::Vec4(b)) => a.dot(b),
+        (Math::Quat(a), Math::Quat(b)) => a.dot(b),
+        (a, b) => {
+            return Err(RuntimeErrorType::InvalidArgument(
+                a.get_type().into(),
+                b.get_type().into(),
+            )
+            .into());
+        }
+    }))
+}
+
+fn cross(args: &[Object]) -> Result<Object> {
+    match (math_arg(&args[0], "vec3")?, math_arg(&args[1], "vec3")?) {
+        (Math::Vec3(a), Math::Vec3(b)) => Ok(Object::Math(Math::Vec3(a.cross(b)))),
+        (Math::Vec3(_), other) | (other, _) => {
+            Err(RuntimeErrorType::InvalidArgument("vec3".into(), other.get_type().into()).into())
+        }
+    }
+}
+
+fn length(args: &[Object]) -> Result<Object> {
+    Ok(Object::Number(match math_arg(&args[0], "vector")? {
+        Math::Vec2(v) => v.magnitude(),
+        Math::Vec3(v) => v.magnitude(),
+        Math::Vec4(v) => v.magnitude(),
+        Math::Quat(q) => q.magnitude(),
+        other => {
+            return Err(RuntimeErrorType::InvalidArgument(
+                "vector".into(),
+                other.get_type().into(),
+            )
+            .into());
+        }
+    }))
+}
+
+fn normalize(args: &[Object]) -> Result<Object> {
+    Ok(Object::Math(match math_arg(&args[0], "vector")? {
+        Math::Vec2(v) => Math::Vec2(v.normalize()),
+        Math::Vec3(v) => Math::Vec3(v.normalize()),
+        Math::Vec4(v) => Math::Vec4(v.normalize()),
+        Math::Quat(q) => Math::Quat(q.normalize()),
+        other => {
+            return Err(RuntimeErrorType::InvalidArgument(
+                "vector".into(),
+                other.get_type().into(),
+            )
+            .into());
+        }
+    }))
+}
+
+fn inverse(args: &[Object]) -> Result<Object> {
+    let value = math_arg(&args[0], "matrix")?;
+    let inverted = match value {
+        Math::Mat3(m) => m.invert().map(Math::Mat3),
+        Math::Mat4(m) => m.invert().map(Math::Mat4),
+        Math::Quat(q) => Some(Math::Quat(q.invert())),
+        other => {
+            return Err(RuntimeErrorType::InvalidArgument(
+                "matrix".into(),
+                other.get_type().into(),
+            )
+            .into());
+        }
+    };
+    inverted
+        .map(Object::Math)
+        .ok_or_else(|| RuntimeErrorType::NotInvertible(value.to_string()).into())
+}
+
+fn transpose(args: &[Object]) -> Result<Object> {
+    Ok(Object::Math(match math_arg(&args[0], "matrix")? {
+        Math::Mat3(m) => Math::Mat3(m.transpose()),
+        Math::Mat4(m) => Math::Mat4(m.transpose()),
+        other => {
+            return Err(RuntimeErrorType::InvalidArgument(
+                "matrix".into(),
+                other.get_type().into(),
+            )
+            .into());
+        }
+    }))
+}
+
+/// `lookAt(eye, target, up)`: a right-handed view matrix.
+fn look_at(args: &[Object]) -> Result<Object> {
+    let eye = Point3::from_vec(vec3_arg(&args[0])?);
+    let target = Point3::from_vec(vec3_arg(&args[1])?);
+    let up = vec3_arg(&args[2])?;
+    Ok(Object::Math(Math::Mat4(Matrix4::look_at_rh(
+        eye, target, up,
+    ))))
+}
+
+/// `perspective(fovy, aspect, near, far)` with `fovy` in degrees.
+fn perspective_matrix(args: &[Object]) -> Result<Object> {
+    let [fovy, aspect, near, far] = [
+        number_arg(&args[0])?,
+        number_arg(&args[1])?,
+        number_arg(&args[2])?,
+        number_arg(&args[3])?,
+    ];
+    Ok(Object::Math(Math::Mat4(perspective(
+        Deg(fovy),
+        aspect,
+        near,
+        far,
+    ))))
+}
+
+impl Neg for Math {
+    type Output = Math;
+
+    fn neg(self) -> Self::Output {
+        self.scale(-1.0)
+    }
+}
+
+impl Display for Math {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let join = |values: &[f64]| {
+            values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let components = self.components();
+        match self {
+            Math::Mat3(_) | Math::Mat4(_) => {
+                let size = if let Math::Mat3(_) = self { 3 } else { 4 };
+                let columns: Vec<_> = components
+                    .chunks(size)
+                    .map(|column| format!("[{}]", join(column)))
+                    .collect();
+                write!(f, "{}({})", self.get_type(), columns.join(", "))
+            }
+            _ => write!(f, "{}({})", self.get_type(), join(&components)),
+        }
+    }
+}
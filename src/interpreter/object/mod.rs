@@ -4,21 +4,31 @@ use crate::interpreter::error::RuntimeErrorType;
 use crate::interpreter::error::{InterpreterError, Result};
 use crate::interpreter::object::callable::Callable;
 use crate::interpreter::object::class::Class;
+use crate::interpreter::object::enumeration::{Enum, Variant};
+use crate::interpreter::object::freeze::Freezable;
 use crate::interpreter::object::instance::Instance;
+use crate::interpreter::object::math::Math;
 use crate::interpreter::object::native_object::NativeObject;
+use crate::interpreter::object::traits::Trait;
 use crate::interpreter::parser::resolver::SomeFun;
 use ordered_float::OrderedFloat;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
-use std::ops::{Add, Deref, Div, Mul, Neg, Not, Sub};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Deref, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 use std::sync::{Arc, RwLock};
 
 pub mod callable;
 pub mod class;
+pub mod enumeration;
+pub mod freeze;
 pub mod instance;
+pub mod list;
+pub mod math;
 pub mod native_object;
+pub mod sequence;
+pub mod traits;
 
 #[derive(Debug, Clone, Default)]
 pub enum Object {
@@ -27,14 +37,19 @@ pub enum Object {
     Bool(bool),
     Callable(Callable),
     Class(Box<Class>),
+    Trait(Box<Trait>),
+    Enum(Enum),
+    Variant(Variant),
     Instance(Instance),
     NativeObject(NativeObject),
     Arc(Arc<Object>),
     #[default]
     Nil,
     Void,
-    List(Arc<RwLock<Vec<Object>>>),
-    Dictionary(Arc<RwLock<HashMap<String, Object>>>),
+    List(Arc<Freezable<Vec<Object>>>),
+    Dictionary(Arc<Freezable<HashMap<String, Object>>>),
+    Range(f64, f64),
+    Math(Math),
 }
 
 impl Eq for Object {}
@@ -49,6 +64,9 @@ impl Hash for Object {
             Bool(b) => b.hash(state),
             Callable(c) => c.hash(state),
             Class(c) => c.hash(state),
+            Trait(t) => t.hash(state),
+            Enum(e) => e.hash(state),
+            Variant(v) => v.hash(state),
             Instance(i) => i.hash(state),
             NativeObject(n) => n.hash(state),
             Arc(o) => o.hash(state),
@@ -61,6 +79,15 @@ impl Hash for Object {
                     v.hash(state);
                 }
             }
+            Range(start, end) => {
+                OrderedFloat(*start).hash(state);
+                OrderedFloat(*end).hash(state);
+            }
+            Math(value) => {
+                for component in value.components() {
+                    OrderedFloat(component).hash(state);
+                }
+            }
         }
     }
 }
@@ -75,11 +102,16 @@ impl Object {
             Object::Void => "void".into(),
             Object::Callable { .. } => "<callable>".into(),
             Object::Class(class) => class.to_string(),
+            Object::Trait(value) => value.to_string(),
+            Object::Enum(value) => value.to_string(),
+            Object::Variant(value) => value.get_enum_name().into(),
             Object::Instance(instance) => instance.to_string(),
             Object::NativeObject(_) => "<native object>".into(),
             Object::Arc(obj) => obj.get_type(),
             Object::List(_) => "list".into(),
             Object::Dictionary(_) => "dictionary".into(),
+            Object::Range(..) => "range".into(),
+            Object::Math(value) => value.get_type().into(),
         }
     }
 
@@ -95,10 +127,6 @@ impl Object {
         ))
     }
 
-    pub fn class(name: &str, methods: HashMap<String, Object>, superclass: Option<Object>) -> Self {
-        Self::Class(b!(Class::new(name.to_string(), methods, superclass)))
-    }
-
     pub fn bind(&self, obj: Instance) -> Result<Object> {
         match self {
             Object::Callable(callable) => {
@@ -128,30 +156,198 @@ impl Object {
         }
     }
 
-    pub fn get_field(&self, key: Object) -> Option<Object> {
-        if let Object::Dictionary(map) = self {
-            let Object::String(key) = key else {
-                return None;
-            };
-            return Some(
-                map.read()
-                    .unwrap()
-                    .get(&key)
-                    .cloned()
-                    .unwrap_or(Object::Nil),
-            );
+    /// Reads `self[key]`. Lists are indexed by integer numbers, dictionaries
+    /// and instances by strings (numbers are accepted as dictionary keys in
+    /// their printed form). Missing list elements and dictionary entries
+    /// read as `nil`.
+    pub fn get_field(&self, key: Object) -> Result<Object> {
+        match self.inner() {
+            Object::List(list) => {
+                let i = key.as_index()?;
+                Ok(list.read().unwrap().get(i).cloned().unwrap_or(Object::Nil))
+            }
+            Object::Dictionary(map) => Ok(map
+                .read()
+                .unwrap()
+                .get(&key.as_key()?)
+                .cloned()
+                .unwrap_or(Object::Nil)),
+            Object::Instance(instance) => instance.get_field(&key.as_key()?),
+            Object::Class(class) => class.get_static(&key.as_key()?),
+            Object::Enum(value) => value.get(&key.as_key()?),
+            Object::Variant(value) => value.get_field(&key),
+            Object::Math(value) => value.index(key.as_index()?),
+            obj => Err(RuntimeErrorType::NotIndexable(obj.get_type()).into()),
         }
-        if let Object::List(list) = self {
-            let Object::Number(i) = key else { return None };
-            return Some(
-                list.read()
-                    .unwrap()
-                    .get(i as usize)
-                    .unwrap_or(&Object::Nil)
-                    .clone(),
-            );
+    }
+
+    /// Writes `self[key] = value` following the same rules as `get_field`.
+    pub fn set_field(&self, key: Object, value: Object) -> Result<()> {
+        if freeze::is_frozen(self) {
+            return Err(RuntimeErrorType::FrozenValue(self.get_type()).into());
+        }
+        match self.inner() {
+            Object::List(list) => {
+                let i = key.as_index()?;
+                let mut list = list.write().unwrap();
+                let len = list.len();
+                let Some(elm) = list.get_mut(i) else {
+                    return Err(RuntimeErrorType::IndexOutOfBounds(i, len).into());
+                };
+                *elm = value;
+            }
+            Object::Dictionary(map) => {
+                map.write().unwrap().insert(key.as_key()?, value);
+            }
+            Object::Instance(instance) => instance.set_field(&key.as_key()?, value),
+            Object::Class(class) => class.set_static(&key.as_key()?, value),
+            Object::Math(value) => {
+                return Err(
+                    RuntimeErrorType::CannotAssignComponent(value.get_type().into()).into(),
+                );
+            }
+            obj => return Err(RuntimeErrorType::NotIndexable(obj.get_type()).into()),
+        }
+        Ok(())
+    }
+
+    /// Iterates `(key, value)` pairs: index and element for lists and ranges,
+    /// key and value for dictionaries. Lists and dictionaries are snapshotted,
+    /// so the loop body may mutate them freely.
+    pub fn entries(&self) -> Result<Box<dyn Iterator<Item = (Object, Object)> + Send>> {
+        match self.inner() {
+            Object::List(list) => Ok(b!(list
+                .read()
+                .unwrap()
+                .clone()
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| (Object::Number(i as f64), value)))),
+            Object::Dictionary(map) => Ok(b!(map
+                .read()
+                .unwrap()
+                .clone()
+                .into_iter()
+                .map(|(key, value)| (Object::String(key), value)))),
+            Object::Range(start, end) => {
+                let (start, end) = (*start, *end);
+                Ok(b!((0..)
+                    .map(move |i| (i, start + i as f64))
+                    .take_while(move |(_, value)| *value < end)
+                    .map(|(i, value)| (
+                        Object::Number(i as f64),
+                        Object::Number(value)
+                    ))))
+            }
+            obj => Err(RuntimeErrorType::NotIterable(obj.get_type()).into()),
+        }
+    }
+
+    /// Splits a two-element list into its elements.
+    pub fn as_pair(&self) -> Result<(Object, Object)> {
+        if let Object::List(list) = self.inner()
+            && let [first, second] = list.read().unwrap().as_slice()
+        {
+            return Ok((first.clone(), second.clone()));
+        }
+        Err(RuntimeErrorType::ExpectedPair(self.to_string()).into())
+    }
+
+    fn as_index(&self) -> Result<usize> {
+        match self.inner() {
+            Object::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Ok(*n as usize),
+            Object::Number(n) => Err(RuntimeErrorType::InvalidIndex(n.to_string()).into()),
+            _ => Err(RuntimeErrorType::MustBeANumber.into()),
+        }
+    }
+
+    /// `+` from edition 2026 on: two lists give a new list holding the
+    /// elements of both, anything else added to a list is an error. Other
+    /// values add as usual.
+    pub fn concat(self, rhs: Object) -> Result<Object> {
+        match (self.inner(), rhs.inner()) {
+            (Object::List(a), Object::List(b)) => {
+                let mut list = a.read().unwrap().clone();
+                list.extend(b.read().unwrap().iter().cloned());
+                Ok(Object::List(Arc::new(Freezable::new(list))))
+            }
+            (Object::List(_), _) | (_, Object::List(_)) => {
+                Err(RuntimeErrorType::CannotAddTypes(self.get_type(), rhs.get_type()).into())
+            }
+            _ => self + rhs,
+        }
+    }
+
+    pub fn as_key(&self) -> Result<String> {
+        match self.inner() {
+            Object::String(key) => Ok(key.clone()),
+            Object::Number(n) => Ok(n.to_string()),
+            obj => Err(RuntimeErrorType::InvalidKey(obj.get_type()).into()),
+        }
+    }
+
+    /// Builds the half-open range `self..rhs`.
+    pub fn range(self, rhs: Object) -> Result<Object> {
+        match (self.inner(), rhs.inner()) {
+            (Object::Number(start), Object::Number(end)) => Ok(Object::Range(*start, *end)),
+            _ => Err(RuntimeErrorType::CannotMakeRange(self.get_type(), rhs.get_type()).into()),
+        }
+    }
+
+    pub fn pow(self, rhs: Object) -> Result<Object> {
+        match (&self, &rhs) {
+            (Object::Number(a), Object::Number(b)) => Ok(Object::Number(a.powf(*b))),
+            (Object::Arc(rc), _) => rc.clone_into_rc().pow(rhs),
+            (_, Object::Arc(rc)) => self.pow(rc.clone_into_rc()),
+            _ => Err(
+                RuntimeErrorType::CannotExponentiateTypes(self.get_type(), rhs.get_type()).into(),
+            ),
+        }
+    }
+
+    pub fn bit_not(self) -> Result<Object> {
+        match &self {
+            Object::Number(_) => Object::from_integer(!self.as_integer()?),
+            Object::Arc(rc) => rc.clone_into_rc().bit_not(),
+            _ => Err(RuntimeErrorType::CannotBitwiseNotType(self.get_type()).into()),
+        }
+    }
+
+    /// A bitwise operand, which must be an integer within ±2^53 so that it
+    /// converts exactly.
+    fn as_integer(&self) -> Result<i64> {
+        match self {
+            Object::Number(n) if n.abs() > MAX_SAFE_INTEGER as f64 => {
+                Err(RuntimeErrorType::IntegerOutOfRange(self.to_string()).into())
+            }
+            Object::Number(n) if n.is_finite() && n.fract() == 0.0 => Ok(*n as i64),
+            _ => Err(RuntimeErrorType::ExpectedIntegerOperand(self.to_string()).into()),
+        }
+    }
+
+    /// The result of a bitwise operator, which must be within ±2^53 to be
+    /// exactly representable.
+    fn from_integer(n: i64) -> Result<Object> {
+        match n.unsigned_abs() > MAX_SAFE_INTEGER as u64 {
+            true => Err(RuntimeErrorType::IntegerOutOfRange(n.to_string()).into()),
+            false => Ok(Object::Number(n as f64)),
+        }
+    }
+
+    fn bitwise(self, rhs: Object, op: &str, f: fn(i64, i64) -> Result<i64>) -> Result<Object> {
+        match (&self, &rhs) {
+            (Object::Number(_), Object::Number(_)) => {
+                Object::from_integer(f(self.as_integer()?, rhs.as_integer()?)?)
+            }
+            (Object::Arc(rc), _) => rc.clone_into_rc().bitwise(rhs, op, f),
+            (_, Object::Arc(rc)) => self.bitwise(rc.clone_into_rc(), op, f),
+            _ => Err(RuntimeErrorType::CannotApplyBitwiseOperator(
+                op.into(),
+                self.get_type(),
+                rhs.get_type(),
+            )
+            .into()),
         }
-        None
     }
 }
 
@@ -161,6 +357,7 @@ impl Neg for Object {
     fn neg(self) -> Self::Output {
         match self {
             Object::Number(n) => Ok(Object::Number(-n)),
+            Object::Math(value) => Ok(Object::Math(-value)),
             Object::Arc(rc) => -rc.clone_into_rc(),
             _ => Err(RuntimeErrorType::CannotNegateType(self.get_type()).into()),
         }
@@ -200,12 +397,20 @@ impl Add for Object {
         match (&self, &rhs) {
             (Object::Number(a), Object::Number(b)) => Ok(Object::Number(a + b)),
             (Object::String(a), Object::String(b)) => Ok(Object::String(a.to_owned() + b)),
+            (Object::List(_), _) if freeze::is_frozen(&self) => {
+                Err(RuntimeErrorType::FrozenValue(self.get_type()).into())
+            }
             (Object::List(a), _) => {
                 a.write().unwrap().push(rhs.clone());
                 Ok(Object::List(a.clone()))
             }
             (Object::Arc(rc), _) => rc.clone_into_rc() + rhs,
             (_, Object::Arc(rc)) => self + rc.clone_into_rc(),
+            (Object::Math(_), _) | (_, Object::Math(_)) => {
+                Math::add(&self, &rhs).ok_or_else(|| {
+                    RuntimeErrorType::CannotAddTypes(self.get_type(), rhs.get_type()).into()
+                })
+            }
             _ => Err(RuntimeErrorType::CannotAddTypes(self.get_type(), rhs.get_type()).into()),
         }
     }
@@ -219,6 +424,11 @@ impl Sub for Object {
             (Object::Number(a), Object::Number(b)) => Ok(Object::Number(a - b)),
             (Object::Arc(rc), _) => rc.clone_into_rc() - rhs,
             (_, Object::Arc(rc)) => self - rc.clone_into_rc(),
+            (Object::Math(_), _) | (_, Object::Math(_)) => {
+                Math::sub(&self, &rhs).ok_or_else(|| {
+                    RuntimeErrorType::CannotSubtractTypes(self.get_type(), rhs.get_type()).into()
+                })
+            }
             _ => Err(RuntimeErrorType::CannotSubtractTypes(self.get_type(), rhs.get_type()).into()),
         }
     }
@@ -232,6 +442,11 @@ impl Mul for Object {
             (Object::Number(a), Object::Number(b)) => Ok(Object::Number(a * b)),
             (Object::Arc(rc), _) => rc.clone_into_rc() * rhs,
             (_, Object::Arc(rc)) => self * rc.clone_into_rc(),
+            (Object::Math(_), _) | (_, Object::Math(_)) => {
+                Math::mul(&self, &rhs).ok_or_else(|| {
+                    RuntimeErrorType::CannotMultiplyTypes(self.get_type(), rhs.get_type()).into()
+                })
+            }
             _ => Err(RuntimeErrorType::CannotMultiplyTypes(self.get_type(), rhs.get_type()).into()),
         }
     }
@@ -245,11 +460,80 @@ impl Div for Object {
             (Object::Number(a), Object::Number(b)) => Ok(Object::Number(a / b)),
             (Object::Arc(rc), _) => rc.clone_into_rc() / rhs,
             (_, Object::Arc(rc)) => self / rc.clone_into_rc(),
+            (Object::Math(_), _) | (_, Object::Math(_)) => {
+                Math::div(&self, &rhs).ok_or_else(|| {
+                    RuntimeErrorType::CannotDivideTypes(self.get_type(), rhs.get_type()).into()
+                })
+            }
             _ => Err(RuntimeErrorType::CannotDivideTypes(self.get_type(), rhs.get_type()).into()),
         }
     }
 }
 
+impl Rem for Object {
+    type Output = Result<Object>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        match (&self, &rhs) {
+            (Object::Number(a), Object::Number(b)) => Ok(Object::Number(a.rem_euclid(*b))),
+            (Object::Arc(rc), _) => rc.clone_into_rc() % rhs,
+            (_, Object::Arc(rc)) => self % rc.clone_into_rc(),
+            _ => Err(RuntimeErrorType::CannotModuloTypes(self.get_type(), rhs.get_type()).into()),
+        }
+    }
+}
+
+impl BitAnd for Object {
+    type Output = Result<Object>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.bitwise(rhs, "&", |a, b| Ok(a & b))
+    }
+}
+
+impl BitOr for Object {
+    type Output = Result<Object>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.bitwise(rhs, "|", |a, b| Ok(a | b))
+    }
+}
+
+impl BitXor for Object {
+    type Output = Result<Object>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.bitwise(rhs, "^", |a, b| Ok(a ^ b))
+    }
+}
+
+/// Largest magnitude up to which every integer is exactly a number.
+const MAX_SAFE_INTEGER: i64 = 1 << 53;
+
+/// `count` as the right operand of `<<` or `>>`, which must be in `0..64`.
+fn shift_count(count: i64) -> Result<u32> {
+    match count {
+        0..64 => Ok(count as u32),
+        _ => Err(RuntimeErrorType::InvalidShiftCount(count).into()),
+    }
+}
+
+impl Shl for Object {
+    type Output = Result<Object>;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.bitwise(rhs, "<<", |a, b| Ok(a << shift_count(b)?))
+    }
+}
+
+impl Shr for Object {
+    type Output = Result<Object>;
+
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.bitwise(rhs, ">>", |a, b| Ok(a >> shift_count(b)?))
+    }
+}
+
 impl PartialEq<Self> for Object {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -259,6 +543,10 @@ impl PartialEq<Self> for Object {
             (Object::Nil, Object::Nil) => true,
             (Object::Void, Object::Void) => true,
             (Object::Callable(callable), Object::Callable(callable2)) => callable == callable2,
+            (Object::Instance(instance), Object::Instance(instance2)) => instance == instance2,
+            (Object::Trait(a), Object::Trait(b)) => a == b,
+            (Object::Enum(a), Object::Enum(b)) => a == b,
+            (Object::Variant(a), Object::Variant(b)) => a == b,
             (Object::Arc(rc), _) => &rc.clone_into_rc() == other,
             (_, Object::Arc(rc)) => self == &rc.clone_into_rc(),
             (Object::Dictionary(dict), Object::Dictionary(dict2)) => {
@@ -267,6 +555,10 @@ impl PartialEq<Self> for Object {
             (Object::List(dict), Object::List(dict2)) => {
                 *dict.read().unwrap() == *dict2.read().unwrap()
             }
+            (Object::Range(start, end), Object::Range(start2, end2)) => {
+                start == start2 && end == end2
+            }
+            (Object::Math(a), Object::Math(b)) => a == b,
             _ => false,
         }
     }
@@ -296,8 +588,12 @@ impl Display for Object {
             Object::Void => write!(f, ""),
             Object::Callable(callable) => write!(f, "{}", callable),
             Object::Class(class) => write!(f, "{}", class),
+            Object::Trait(value) => write!(f, "{}", value),
+            Object::Enum(value) => write!(f, "{}", value),
+            Object::Variant(value) => write!(f, "{}", value),
             Object::Instance(instance) => write!(f, "{}", instance),
             Object::NativeObject(_) => write!(f, "<native object>"),
+            Object::Math(value) => write!(f, "{}", value),
             Object::Arc(rc) => write!(f, "{}", rc),
             Object::List(list) => write!(
                 f,
@@ -325,6 +621,7 @@ impl Display for Object {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
+            Object::Range(start, end) => write!(f, "{}..{}", start, end),
         }
     }
 }
@@ -0,0 +1,184 @@
+use crate::interpreter::Interpreter;
+use crate::interpreter::error::{Result, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::freeze::Freezable;
+use crate::interpreter::object::native_object::{Native, NativeObject};
+use crate::{b, rc};
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+type Entries = Mutex<Box<dyn Iterator<Item = Object> + Send>>;
+
+/// A lazy, single-pass stream of values. Built-in collections, ranges and
+/// instances following the iterator protocol can all be turned into one.
+///
+/// The protocol: an instance with an `iter()` method is iterated through
+/// whatever `iter()` returns; otherwise an instance with a `next()` method
+/// is its own iterator and is exhausted once `next()` returns
+/// `Iteration.Done`, so `nil` can be yielded like any other value. Only
+/// methods count, a `next` field does not make an instance an iterator.
+#[derive(Clone)]
+pub struct Sequence(Arc<Kind>);
+
+enum Kind {
+    Entries(Entries),
+    Instance(Object),
+    Map(Sequence, Object),
+    Filter(Sequence, Object),
+    Zip(Sequence, Sequence),
+    Enumerate(Sequence, AtomicUsize),
+    Take(Sequence, AtomicUsize),
+}
+
+impl Sequence {
+    fn new(kind: Kind) -> Self {
+        Self(rc!(kind))
+    }
+
+    pub fn from_object(interpreter: &mut Interpreter, obj: Object) -> Result<Self> {
+        match obj.inner() {
+            Object::List(_) | Object::Range(..) => {
+                Ok(Self::from_entries(obj.entries()?.map(|(_, value)| value)))
+            }
+            Object::Dictionary(_) => Ok(Self::from_entries(obj.entries()?.map(|(key, _)| key))),
+            Object::NativeObject(native) => match native.clone().extract().downcast_ref::<Self>() {
+                Some(sequence) => Ok(sequence.clone()),
+                None => Err(RuntimeErrorType::NotIterable(obj.get_type()).into()),
+            },
+            Object::Instance(instance) => {
+                if let Some(iter) = instance.get_method("iter")? {
+                    let iterator = interpreter.call_object(iter, vec![])?;
+                    // An iterator's `iter()` may return the iterator itself.
+                    if iterator != obj {
+                        return Self::from_object(interpreter, iterator);
+                    }
+                }
+                match instance.get_method("next")? {
+                    Some(_) => Ok(Self::new(Kind::Instance(obj.clone()))),
+                    None => Err(RuntimeErrorType::NotIterable(obj.get_type()).into()),
+                }
+            }
+            _ => Err(RuntimeErrorType::NotIterable(obj.get_type()).into()),
+        }
+    }
+
+    /// Key/value pairs of a list, dictionary or range as two-element lists.
+    pub fn pairs(obj: &Object) -> Result<Self> {
+        Ok(Self::from_entries(obj.entries()?.map(|(key, value)| {
+            Object::List(rc!(Freezable::new(vec![key, value])))
+        })))
+    }
+
+    fn from_entries(entries: impl Iterator<Item = Object> + Send + 'static) -> Self {
+        Self::new(Kind::Entries(Mutex::new(b!(entries))))
+    }
+
+    pub fn map(self, func: Object) -> Self {
+        Self::new(Kind::Map(self, func))
+    }
+
+    pub fn filter(self, func: Object) -> Self {
+        Self::new(Kind::Filter(self, func))
+    }
+
+    pub fn zip(self, other: Sequence) -> Self {
+        Self::new(Kind::Zip(self, other))
+    }
+
+    pub fn enumerate(self) -> Self {
+        Self::new(Kind::Enumerate(self, AtomicUsize::new(0)))
+    }
+
+    pub fn take(self, n: usize) -> Self {
+        Self::new(Kind::Take(self, AtomicUsize::new(n)))
+    }
+
+    /// Pulls the next value, or `None` once the sequence is exhausted.
+    pub fn next(&self, interpreter: &mut Interpreter) -> Result<Option<Object>> {
+        match self.0.as_ref() {
+            Kind::Entries(entries) => Ok(entries.lock().unwrap().next()),
+            Kind::Instance(obj) => {
+                let Object::Instance(instance) = obj.inner() else {
+                    unreachable!()
+                };
+                let Some(next) = instance.get_method("next")? else {
+                    return Err(RuntimeErrorType::NotIterable(obj.get_type()).into());
+                };
+                match interpreter.call_object(next, vec![])? {
+                    Object::Variant(variant)
+                        if variant.to_engine_string("Iteration").as_deref() == Some("done") =>
+                    {
+                        Ok(None)
+                    }
+                    value => Ok(Some(value)),
+                }
+            }
+            Kind::Map(inner, func) => match inner.next(interpreter)? {
+                Some(value) => Ok(Some(interpreter.call_object(func.clone(), vec![value])?)),
+                None => Ok(None),
+            },
+            Kind::Filter(inner, func) => {
+                while let Some(value) = inner.next(interpreter)? {
+                    let keep = interpreter.call_object(func.clone(), vec![value.clone()])?;
+                    if interpreter.is_truly(&keep)? {
+                        return Ok(Some(value));
+                    }
+                }
+                Ok(None)
+            }
+            Kind::Zip(left, right) => {
+                let (Some(a), Some(b)) = (left.next(interpreter)?, right.next(interpreter)?) else {
+                    return Ok(None);
+                };
+                Ok(Some(Object::List(rc!(Freezable::new(vec![a, b])))))
+            }
+            Kind::Enumerate(inner, index) => match inner.next(interpreter)? {
+                Some(value) => {
+                    let i = index.fetch_add(1, Ordering::Relaxed);
+                    Ok(Some(Object::List(rc!(Freezable::new(vec![
+                        Object::Number(i as f64),
+                        value
+                    ])))))
+                }
+                None => Ok(None),
+            },
+            Kind::Take(inner, remaining) => {
+                if remaining
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                    .is_err()
+                {
+                    return Ok(None);
+                }
+                inner.next(interpreter)
+            }
+        }
+    }
+
+    /// Drains the sequence into a list.
+    pub fn collect(&self, interpreter: &mut Interpreter) -> Result<Object> {
+        let mut values = vec![];
+        while let Some(value) = self.next(interpreter)? {
+            values.push(value);
+        }
+        Ok(Object::List(rc!(Freezable::new(values))))
+    }
+}
+
+impl From<Sequence> for Object {
+    fn from(value: Sequence) -> Self {
+        Object::NativeObject(NativeObject::new(b!(value)))
+    }
+}
+
+impl Debug for Sequence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<sequence>")
+    }
+}
+
+impl Native for Sequence {
+    fn clone_box(&self) -> Box<dyn Native> {
+        b!(self.clone())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::interpreter::object::Object;
+use crate::rc;
+use crate::utils::next_id;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct Trait {
+    id: u64,
+    name: Arc<String>,
+    methods: Arc<HashMap<String, Object>>,
+    required: Arc<Vec<String>>,
+}
+
+impl Trait {
+    pub fn new(name: String, methods: HashMap<String, Object>, required: Vec<String>) -> Self {
+        Self {
+            id: next_id(),
+            name: rc!(name),
+            methods: rc!(methods),
+            required: rc!(required),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn find_method(&self, name: &str) -> Option<Object> {
+        self.methods.get(name).cloned()
+    }
+
+    pub fn get_required(&self) -> &[String] {
+        &self.required
+    }
+}
+
+impl Display for Trait {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl PartialEq for Trait {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Trait {}
+
+impl Hash for Trait {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.name.hash(state);
+    }
+}
@@ -75,6 +75,48 @@ pub enum ParserErrorType {
     ExpectedLeftBraceBeforeObj,
     ExpectedIdentAfterBufferDecl,
     ExpectedComma,
+    CantBreakOutsideLoop,
+    CantContinueOutsideLoop,
+    ExpectedRightBraceAfterInterpolation,
+    ExpectedLeftBraceAfterMatch,
+    ExpectedFatArrowAfterPattern,
+    ExpectedPattern,
+    ExpectedRightBracketAfterRest,
+    UnreachableMatchArm,
+    DuplicateBinding(String),
+    ExpectedInitializerForPattern,
+    VariadicParamMustBeLast,
+    VariadicParamCantHaveDefault,
+    RequiredParamAfterDefault,
+    PositionalArgAfterNamed,
+    ExpectedLeftBraceAfterTry,
+    ExpectedRightParenAfterCatch,
+    ExpectedLeftBraceAfterCatch,
+    ExpectedLeftBraceAfterFinally,
+    ExpectedCatchOrFinally,
+    GetterHasParams,
+    SetterNeedsOneParam,
+    ExpectedSemicolonAfterField,
+    ExpectedIdentAfterTraitDecl,
+    ExpectedTraitIdent,
+    MissingTraitMethod(String, String),
+    ExpectedIdentAfterEnumDecl,
+    ExpectedVariantName,
+    DuplicateVariant(String),
+    UndefinedVariant(String, String),
+    ConstWithoutInitializer,
+    CantAssignToConst(String),
+    ExpectedImportName,
+    ExpectedRightBraceAfterImports,
+    ExpectedFromAfterImports,
+    ExpectedModuleName,
+    UnknownImport(String, String),
+    DuplicateImport(String),
+    ImportCollision(String),
+    ExpectedExportName,
+    ExpectedRightBraceAfterExports,
+    ExpectedFromAfterExportAll,
+    DuplicateExport(String),
 }
 
 impl Display for ParserErrorType {
@@ -165,6 +207,134 @@ impl Display for ParserErrorType {
                 write!(f, "Expected identifier after buffer declaration!")
             }
             ParserErrorType::ExpectedComma => write!(f, "Expected ','!"),
+            ParserErrorType::CantBreakOutsideLoop => {
+                write!(f, "Can't use 'break' outside of a loop!")
+            }
+            ParserErrorType::CantContinueOutsideLoop => {
+                write!(f, "Can't use 'continue' outside of a loop!")
+            }
+            ParserErrorType::ExpectedRightBraceAfterInterpolation => {
+                write!(f, "Expected '}}' after interpolated expression!")
+            }
+            ParserErrorType::ExpectedLeftBraceAfterMatch => {
+                write!(f, "Expected '{{' after match subject!")
+            }
+            ParserErrorType::ExpectedFatArrowAfterPattern => {
+                write!(f, "Expected '=>' after pattern!")
+            }
+            ParserErrorType::ExpectedPattern => write!(f, "Expected pattern!"),
+            ParserErrorType::ExpectedRightBracketAfterRest => {
+                write!(f, "Expected ']' after rest pattern!")
+            }
+            ParserErrorType::UnreachableMatchArm => {
+                write!(
+                    f,
+                    "Unreachable match arm, an earlier arm already covers it!"
+                )
+            }
+            ParserErrorType::VariadicParamMustBeLast => {
+                write!(f, "Variadic parameter must be the last one!")
+            }
+            ParserErrorType::VariadicParamCantHaveDefault => {
+                write!(f, "Variadic parameter can't have a default value!")
+            }
+            ParserErrorType::RequiredParamAfterDefault => {
+                write!(
+                    f,
+                    "Parameter without a default can't follow one with a default!"
+                )
+            }
+            ParserErrorType::PositionalArgAfterNamed => {
+                write!(f, "Positional argument can't follow a named one!")
+            }
+            ParserErrorType::ExpectedLeftBraceAfterTry => write!(f, "Expected '{{' after try!"),
+            ParserErrorType::ExpectedRightParenAfterCatch => {
+                write!(f, "Expected ')' after catch variable!")
+            }
+            ParserErrorType::ExpectedLeftBraceAfterCatch => {
+                write!(f, "Expected '{{' after catch!")
+            }
+            ParserErrorType::ExpectedLeftBraceAfterFinally => {
+                write!(f, "Expected '{{' after finally!")
+            }
+            ParserErrorType::ExpectedCatchOrFinally => {
+                write!(f, "Expected 'catch' or 'finally' after try block!")
+            }
+            ParserErrorType::GetterHasParams => write!(f, "Getter can't take parameters!"),
+            ParserErrorType::SetterNeedsOneParam => {
+                write!(f, "Setter must take exactly one parameter!")
+            }
+            ParserErrorType::ExpectedSemicolonAfterField => {
+                write!(f, "Expected ';' after field declaration!")
+            }
+            ParserErrorType::ExpectedIdentAfterTraitDecl => {
+                write!(f, "Expected identifier after trait declaration!")
+            }
+            ParserErrorType::ExpectedTraitIdent => write!(f, "Expected trait identifier!"),
+            ParserErrorType::MissingTraitMethod(name, method) => {
+                write!(
+                    f,
+                    "Class doesn't define method '{}' required by trait '{}'!",
+                    method, name
+                )
+            }
+            ParserErrorType::ExpectedIdentAfterEnumDecl => {
+                write!(f, "Expected identifier after enum declaration!")
+            }
+            ParserErrorType::ExpectedVariantName => write!(f, "Expected variant name!"),
+            ParserErrorType::DuplicateVariant(name) => {
+                write!(f, "Variant '{}' is declared more than once!", name)
+            }
+            ParserErrorType::UndefinedVariant(name, variant) => {
+                write!(f, "Enum '{}' has no variant '{}'!", name, variant)
+            }
+            ParserErrorType::ExpectedImportName => write!(f, "Expected name to import!"),
+            ParserErrorType::ExpectedRightBraceAfterImports => {
+                write!(f, "Expected '}}' after imported names!")
+            }
+            ParserErrorType::ExpectedFromAfterImports => {
+                write!(f, "Expected 'from' after imported names!")
+            }
+            ParserErrorType::ExpectedModuleName => write!(f, "Expected module name after 'as'!"),
+            ParserErrorType::UnknownImport(name, path) => {
+                write!(f, "Module '{}' does not export '{}'!", path, name)
+            }
+            ParserErrorType::DuplicateImport(name) => {
+                write!(f, "Name '{}' is already imported!", name)
+            }
+            ParserErrorType::ImportCollision(name) => {
+                write!(
+                    f,
+                    "Name '{}' is both imported and declared in this file!",
+                    name
+                )
+            }
+            ParserErrorType::ExpectedExportName => write!(f, "Expected name to export!"),
+            ParserErrorType::ExpectedRightBraceAfterExports => {
+                write!(f, "Expected '}}' after exported names!")
+            }
+            ParserErrorType::ExpectedFromAfterExportAll => {
+                write!(f, "Expected 'from' after 'export *'!")
+            }
+            ParserErrorType::DuplicateExport(name) => {
+                write!(f, "Name '{}' is already exported!", name)
+            }
+            ParserErrorType::ConstWithoutInitializer => {
+                write!(f, "Constant must be initialized!")
+            }
+            ParserErrorType::CantAssignToConst(name) => {
+                write!(f, "Can't assign to constant '{}'!", name)
+            }
+            ParserErrorType::ExpectedInitializerForPattern => {
+                write!(f, "Expected '=' and a value to destructure!")
+            }
+            ParserErrorType::DuplicateBinding(name) => {
+                write!(
+                    f,
+                    "Name '{}' is bound more than once in this pattern!",
+                    name
+                )
+            }
         }
     }
 }
@@ -1,31 +1,42 @@
 use crate::b;
 use crate::interpreter::ast::expr::Expr;
 use crate::interpreter::ast::expr::anon_fun::AnonFun;
-use crate::interpreter::ast::expr::assignment::Assign;
+use crate::interpreter::ast::expr::assignment::{Assign, AssignOp};
 use crate::interpreter::ast::expr::binary::Binary;
 use crate::interpreter::ast::expr::call::Call;
 use crate::interpreter::ast::expr::get::{Get, GetType};
 use crate::interpreter::ast::expr::grouping::Grouping;
+use crate::interpreter::ast::expr::interpolation::Interpolation;
 use crate::interpreter::ast::expr::list::List;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::expr::logical::Logical;
+use crate::interpreter::ast::expr::match_expr::{ArmBody, Match, MatchArm};
 use crate::interpreter::ast::expr::object::Obj;
 use crate::interpreter::ast::expr::self_expr::SelfExpr;
-use crate::interpreter::ast::expr::set::Set;
+use crate::interpreter::ast::expr::set::{Set, SetType};
 use crate::interpreter::ast::expr::superclass::Super;
 use crate::interpreter::ast::expr::unary::Unary;
 use crate::interpreter::ast::expr::variable::Variable;
+use crate::interpreter::ast::param::Param;
+use crate::interpreter::ast::pattern::{Pattern, Rest, TYPE_PATTERNS};
 use crate::interpreter::ast::stmt::Stmt;
 use crate::interpreter::ast::stmt::block::Block;
+use crate::interpreter::ast::stmt::break_stmt::Break;
 use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::continue_stmt::Continue;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
+use crate::interpreter::ast::stmt::for_in_stmt::ForIn;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::ast::stmt::if_stmt::If;
 use crate::interpreter::ast::stmt::let_stmt::Let;
 use crate::interpreter::ast::stmt::print::Print;
 use crate::interpreter::ast::stmt::return_stmt::Return;
 use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
-use crate::interpreter::ast::stmt::use_stmt::Use;
+use crate::interpreter::ast::stmt::throw_stmt::Throw;
+use crate::interpreter::ast::stmt::trait_stmt::Trait;
+use crate::interpreter::ast::stmt::try_stmt::{Catch, Try};
+use crate::interpreter::ast::stmt::use_stmt::{Import, Use};
 use crate::interpreter::ast::stmt::while_stmt::While;
 use crate::interpreter::error::Result;
 use crate::interpreter::object::Object;
@@ -37,6 +48,10 @@ use std::marker::PhantomData;
 
 pub mod error;
 pub mod resolver;
+
+/// Parameters plus the statements unpacking destructured parameters.
+type Parameters<T> = (Vec<Param<T>>, Vec<Box<dyn Stmt<T>>>);
+
 pub struct Parser<T> {
     phantom_data: PhantomData<T>,
     tokens: Vec<Token>,
@@ -86,22 +101,118 @@ where
             return self.import();
         }
         if self._match(vec![TokenType::Export]) {
-            return Ok(b!(Export::new(self.previous(), self.declaration()?)));
+            let name = self.previous();
+            if self._match(vec![TokenType::LeftBrace]) {
+                return self.export_list(name);
+            }
+            if self._match(vec![TokenType::Star]) {
+                return self.export_all(name);
+            }
+            return Ok(b!(Export::new(name, self.declaration()?)));
         }
         self.declaration()
     }
 
+    /// `export { a, b as c };`, after the `{`.
+    fn export_list(&mut self, name: Token) -> Result<Box<dyn Stmt<T>>> {
+        let mut names = vec![];
+        while !self.check(TokenType::RightBrace) {
+            let binding =
+                self.consume(TokenType::Identifier, ParserErrorType::ExpectedExportName)?;
+            let alias = if self.check_word("as") {
+                self.advance();
+                self.consume(TokenType::Identifier, ParserErrorType::ExpectedExportName)?
+            } else {
+                binding.clone()
+            };
+            if names.iter().any(|(_, exported): &(Variable, Token)| {
+                exported.get_lexeme() == alias.get_lexeme()
+            }) {
+                return Err(ParserError::new(
+                    alias.clone(),
+                    ParserErrorType::DuplicateExport(alias.get_lexeme().into()),
+                )
+                .into());
+            }
+            names.push((Variable::new(binding), alias));
+            if !self._match(vec![TokenType::Comma]) {
+                break;
+            }
+        }
+        self.consume(
+            TokenType::RightBrace,
+            ParserErrorType::ExpectedRightBraceAfterExports,
+        )?;
+        self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
+
+        Ok(b!(Export::list(name, names)))
+    }
+
+    /// `export * from "x";`, after the `*`. A spliced module's exports stay
+    /// exported, so this is a plain `use` of it.
+    fn export_all(&mut self, name: Token) -> Result<Box<dyn Stmt<T>>> {
+        if !self.check_word("from") {
+            return Err(
+                ParserError::new(self.peek(), ParserErrorType::ExpectedFromAfterExportAll).into(),
+            );
+        }
+        self.advance();
+        let expr = self.expression()?;
+        self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
+
+        Ok(b!(Use::new(name, expr)))
+    }
+
+    /// `use "x";`, `use "x" as m;` or `use {a, b} from "x";`.
     fn import(&mut self) -> Result<Box<dyn Stmt<T>>> {
         let name = self.previous();
+
+        let mut names = None;
+        if self._match(vec![TokenType::LeftBrace]) {
+            let mut list = vec![];
+            while !self.check(TokenType::RightBrace) {
+                list.push(
+                    self.consume(TokenType::Identifier, ParserErrorType::ExpectedImportName)?,
+                );
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+            self.consume(
+                TokenType::RightBrace,
+                ParserErrorType::ExpectedRightBraceAfterImports,
+            )?;
+            if !self.check_word("from") {
+                return Err(ParserError::new(
+                    self.peek(),
+                    ParserErrorType::ExpectedFromAfterImports,
+                )
+                .into());
+            }
+            self.advance();
+            names = Some(list);
+        }
+
         let expr = self.expression()?;
 
+        let import = match names {
+            Some(names) => Import::Names(names),
+            None if self.check_word("as") => {
+                self.advance();
+                Import::Module(
+                    self.consume(TokenType::Identifier, ParserErrorType::ExpectedModuleName)?,
+                )
+            }
+            None => Import::All,
+        };
+
         self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
 
-        Ok(b!(Use::new(name, expr)))
+        Ok(b!(Use::new(name, expr).with_import(import)))
     }
 
     fn declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
-        if self._match(vec![TokenType::Let]) {
+        if self._match(vec![TokenType::Let, TokenType::Const]) {
             return self.let_declaration();
         }
 
@@ -113,6 +224,14 @@ where
             return self.class_declaration();
         }
 
+        if self._match(vec![TokenType::Trait]) {
+            return self.trait_declaration();
+        }
+
+        if self._match(vec![TokenType::Enum]) {
+            return self.enum_declaration();
+        }
+
         self.statement()
     }
 
@@ -131,18 +250,64 @@ where
             super_class = Some(Variable::new(self.previous()));
         }
 
+        let mut mixins = vec![];
+        if self.check_word("with") {
+            self.advance();
+            loop {
+                let mixin =
+                    self.consume(TokenType::Identifier, ParserErrorType::ExpectedTraitIdent)?;
+                mixins.push(Variable::new(mixin));
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
         self.consume(
             TokenType::LeftBrace,
             ParserErrorType::ExpectedLeftBraceBeforeBody,
         )?;
 
         let mut methods = vec![];
+        let mut statics = vec![];
+        let mut getters = vec![];
+        let mut setters = vec![];
+        let mut fields = vec![];
+        let mut static_fields = vec![];
         while !self.check(TokenType::RightBrace) && !self.is_at_end() {
-            match self.fun_declaration()?.downcast::<Fun<T>>() {
-                Ok(func) => methods.push(*func),
-                Err(_) => {
-                    return Err(ParserError::new(self.previous(), ParserErrorType::NotAFunc).into());
+            if self.check_modifier("static") {
+                self.advance();
+                if self.is_field_ahead() {
+                    static_fields.push(self.field_declaration()?);
+                } else {
+                    statics.push(self.method_declaration()?);
                 }
+            } else if self.check_modifier("get") {
+                self.advance();
+                let getter = self.method_declaration()?;
+                if !getter.get_params().is_empty() {
+                    return Err(ParserError::new(
+                        getter.get_name(),
+                        ParserErrorType::GetterHasParams,
+                    )
+                    .into());
+                }
+                getters.push(getter);
+            } else if self.check_modifier("set") {
+                self.advance();
+                let setter = self.method_declaration()?;
+                if setter.get_params().len() != 1 || setter.get_params()[0].is_variadic() {
+                    return Err(ParserError::new(
+                        setter.get_name(),
+                        ParserErrorType::SetterNeedsOneParam,
+                    )
+                    .into());
+                }
+                setters.push(setter);
+            } else if self.is_field_ahead() {
+                fields.push(self.field_declaration()?);
+            } else {
+                methods.push(self.method_declaration()?);
             }
         }
 
@@ -151,7 +316,200 @@ where
             ParserErrorType::ExpectedMatchingBrace,
         )?;
 
-        Ok(b!(Class::new(name, methods, super_class)))
+        let fields = Parser::field_initializer(&name, "<fields>", fields);
+        let static_fields = Parser::field_initializer(&name, "<static fields>", static_fields);
+        Ok(b!(Class::new(name, methods, super_class)
+            .with_mixins(mixins)
+            .with_statics(statics, static_fields)
+            .with_accessors(getters, setters)
+            .with_fields(fields)))
+    }
+
+    fn trait_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let name = self.consume(
+            TokenType::Identifier,
+            ParserErrorType::ExpectedIdentAfterTraitDecl,
+        )?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            ParserErrorType::ExpectedLeftBraceBeforeBody,
+        )?;
+
+        let mut methods = vec![];
+        let mut required = vec![];
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            let method = self.consume(
+                TokenType::Identifier,
+                ParserErrorType::ExpectedIdentAfterFunDecl,
+            )?;
+            self.consume(
+                TokenType::LeftParen,
+                ParserErrorType::ExpectedLeftParenAfterFunIdent,
+            )?;
+            let (params, mut body) = self.parameters()?;
+            if self._match(vec![TokenType::Semicolon]) {
+                required.push(method);
+                continue;
+            }
+            self.consume(
+                TokenType::LeftBrace,
+                ParserErrorType::ExpectedLeftBraceBeforeBody,
+            )?;
+            body.extend(self.block_statement()?);
+            methods.push(Fun::new(method, params, body));
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            ParserErrorType::ExpectedMatchingBrace,
+        )?;
+
+        Ok(b!(Trait::new(name, methods, required)))
+    }
+
+    fn enum_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let name = self.consume(
+            TokenType::Identifier,
+            ParserErrorType::ExpectedIdentAfterEnumDecl,
+        )?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            ParserErrorType::ExpectedLeftBraceBeforeBody,
+        )?;
+
+        let mut variants: Vec<(Token, Vec<Token>)> = vec![];
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            let variant =
+                self.consume(TokenType::Identifier, ParserErrorType::ExpectedVariantName)?;
+            if variants
+                .iter()
+                .any(|(prev, _)| prev.get_lexeme() == variant.get_lexeme())
+            {
+                return Err(ParserError::new(
+                    variant.clone(),
+                    ParserErrorType::DuplicateVariant(variant.get_lexeme().into()),
+                )
+                .into());
+            }
+
+            let mut fields: Vec<Token> = vec![];
+            if self._match(vec![TokenType::LeftParen]) && !self._match(vec![TokenType::RightParen])
+            {
+                loop {
+                    let field =
+                        self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?;
+                    if fields
+                        .iter()
+                        .any(|prev| prev.get_lexeme() == field.get_lexeme())
+                    {
+                        return Err(ParserError::new(
+                            field.clone(),
+                            ParserErrorType::DuplicateBinding(field.get_lexeme().into()),
+                        )
+                        .into());
+                    }
+                    fields.push(field);
+                    if !self._match(vec![TokenType::Comma]) {
+                        break;
+                    }
+                }
+                self.consume(
+                    TokenType::RightParen,
+                    ParserErrorType::ExpectedRightParenAfterParams,
+                )?;
+            }
+            variants.push((variant, fields));
+
+            if !self._match(vec![TokenType::Comma]) {
+                break;
+            }
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            ParserErrorType::ExpectedMatchingBrace,
+        )?;
+
+        Ok(b!(Enum::new(name, variants)))
+    }
+
+    fn method_declaration(&mut self) -> Result<Fun<T>> {
+        match self.fun_declaration()?.downcast::<Fun<T>>() {
+            Ok(func) => Ok(*func),
+            Err(_) => Err(ParserError::new(self.previous(), ParserErrorType::NotAFunc).into()),
+        }
+    }
+
+    /// `static`, `get` and `set` are only keywords inside a class body and
+    /// only when a member name follows, so they stay usable as method names.
+    /// A contextual keyword such as `with`, `as` or `from`.
+    fn check_word(&self, word: &str) -> bool {
+        self.check(TokenType::Identifier) && self.peek().get_lexeme() == word
+    }
+
+    fn check_modifier(&self, modifier: &str) -> bool {
+        self.check(TokenType::Identifier)
+            && self.peek().get_lexeme() == modifier
+            && self
+                .tokens
+                .get(self.current + 1)
+                .is_some_and(|token| token.get_type() == TokenType::Identifier)
+    }
+
+    fn is_field_ahead(&self) -> bool {
+        self.check(TokenType::Identifier)
+            && self.tokens.get(self.current + 1).is_some_and(|token| {
+                matches!(token.get_type(), TokenType::Equal | TokenType::Semicolon)
+            })
+    }
+
+    /// Parses `name = value;` or `name;` into the assignment `self.name = value`.
+    fn field_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let name = self.advance();
+        let value = if self._match(vec![TokenType::Equal]) {
+            self.expression()?
+        } else {
+            b!(Literal::new(Some(Object::Nil)))
+        };
+        self.consume(
+            TokenType::Semicolon,
+            ParserErrorType::ExpectedSemicolonAfterField,
+        )?;
+
+        let receiver = Token::new(
+            TokenType::Slf,
+            "self",
+            None,
+            name.get_line(),
+            name.get_pos_in_line(),
+        );
+        Ok(b!(StmtExpr::new(b!(Set::new(
+            SetType::Name(name),
+            b!(SelfExpr::new(receiver)),
+            value,
+        )))))
+    }
+
+    /// Collects field assignments into a hidden method run when the class or
+    /// one of its instances is created.
+    fn field_initializer(
+        class: &Token,
+        name: &str,
+        fields: Vec<Box<dyn Stmt<T>>>,
+    ) -> Option<Fun<T>> {
+        if fields.is_empty() {
+            return None;
+        }
+        let name = Token::new(
+            TokenType::Identifier,
+            name,
+            None,
+            class.get_line(),
+            class.get_pos_in_line(),
+        );
+        Some(Fun::new(name, vec![], fields))
     }
 
     fn fun_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
@@ -165,12 +523,25 @@ where
             ParserErrorType::ExpectedLeftParenAfterFunIdent,
         )?;
 
-        let mut params = vec![];
+        let (params, mut body) = self.parameters()?;
+        self.consume(
+            TokenType::LeftBrace,
+            ParserErrorType::ExpectedLeftBraceBeforeBody,
+        )?;
+
+        body.extend(self.block_statement()?);
+        Ok(b!(Fun::new(name, params, body)))
+    }
+
+    /// Parses a parameter list up to and including ')'. Destructuring
+    /// parameters are replaced by hidden names and unpacked by `let`
+    /// statements returned alongside, which go in front of the body.
+    fn parameters(&mut self) -> Result<Parameters<T>> {
+        let mut params: Vec<Param<T>> = vec![];
+        let mut prologue: Vec<Box<dyn Stmt<T>>> = vec![];
 
         if !self.check(TokenType::RightParen) {
-            let param = self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?;
-            params.push(param);
-            while self.check(TokenType::Comma) {
+            loop {
                 if params.len() >= 255 {
                     return Err(ParserError::new(
                         self.peek(),
@@ -178,10 +549,64 @@ where
                     )
                     .into());
                 }
-                self.consume(TokenType::Comma, ParserErrorType::ExpectedComma)?;
-                let param =
-                    self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?;
-                params.push(param);
+                if let Some(last) = params.last()
+                    && last.is_variadic()
+                {
+                    return Err(ParserError::new(
+                        last.get_name().clone(),
+                        ParserErrorType::VariadicParamMustBeLast,
+                    )
+                    .into());
+                }
+
+                if self._match(vec![TokenType::DotDotDot]) {
+                    let name =
+                        self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?;
+                    if self.check(TokenType::Equal) {
+                        return Err(ParserError::new(
+                            self.peek(),
+                            ParserErrorType::VariadicParamCantHaveDefault,
+                        )
+                        .into());
+                    }
+                    params.push(Param::variadic(name));
+                } else {
+                    let name =
+                        if self.check(TokenType::LeftBracket) || self.check(TokenType::LeftBrace) {
+                            let pattern = self.pattern()?;
+                            let token = pattern.get_token();
+                            let param = Token::new(
+                                TokenType::Identifier,
+                                &format!("<param {}>", params.len()),
+                                None,
+                                token.get_line(),
+                                token.get_pos_in_line(),
+                            );
+                            prologue.push(b!(Let::with_pattern(
+                                pattern,
+                                b!(Variable::new(param.clone()))
+                            )));
+                            param
+                        } else {
+                            self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?
+                        };
+
+                    if self._match(vec![TokenType::Equal]) {
+                        params.push(Param::with_default(name, self.expression()?));
+                    } else if params.iter().any(|param| param.get_default().is_some()) {
+                        return Err(ParserError::new(
+                            name,
+                            ParserErrorType::RequiredParamAfterDefault,
+                        )
+                        .into());
+                    } else {
+                        params.push(Param::new(name));
+                    }
+                }
+
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
+                }
             }
         }
 
@@ -189,21 +614,36 @@ where
             TokenType::RightParen,
             ParserErrorType::ExpectedRightParenAfterParams,
         )?;
-        self.consume(
-            TokenType::LeftBrace,
-            ParserErrorType::ExpectedLeftBraceBeforeBody,
-        )?;
 
-        let body = self.block_statement()?;
-        Ok(b!(Fun::new(name, params, body)))
+        Ok((params, prologue))
     }
 
+    /// `let` or `const` declaration, the keyword being the previous token.
     fn let_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let is_const = self.previous().get_type() == TokenType::Const;
+
+        if self.check(TokenType::LeftBracket) || self.check(TokenType::LeftBrace) {
+            let pattern = self.pattern()?;
+            self.consume(
+                TokenType::Equal,
+                ParserErrorType::ExpectedInitializerForPattern,
+            )?;
+            let initializer = self.expression()?;
+            self.consume(
+                TokenType::Semicolon,
+                ParserErrorType::ExpectedSemicolonAfterVarDecl,
+            )?;
+            let stmt = Let::with_pattern(pattern, initializer);
+            return Ok(b!(if is_const { stmt.constant() } else { stmt }));
+        }
+
         let name = self.consume(TokenType::Identifier, ParserErrorType::ExpectedVariableName)?;
 
         let mut initializer = None;
         if self._match(vec![TokenType::Equal]) {
             initializer = Some(self.expression()?);
+        } else if is_const {
+            return Err(ParserError::new(name, ParserErrorType::ConstWithoutInitializer).into());
         }
 
         self.consume(
@@ -211,7 +651,8 @@ where
             ParserErrorType::ExpectedSemicolonAfterVarDecl,
         )?;
 
-        Ok(b!(Let::new(name, initializer)))
+        let stmt = Let::new(name, initializer);
+        Ok(b!(if is_const { stmt.constant() } else { stmt }))
     }
 
     fn statement(&mut self) -> Result<Box<dyn Stmt<T>>> {
@@ -238,6 +679,36 @@ where
             return self.return_statement();
         }
 
+        if self.check(TokenType::Match) {
+            // A `match` in statement position needs no trailing ';'.
+            let expr = self.expression()?;
+            self._match(vec![TokenType::Semicolon]);
+            return Ok(b!(StmtExpr::new(expr)));
+        }
+
+        if self._match(vec![TokenType::Break]) {
+            let token = self.previous();
+            self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
+            return Ok(b!(Break::new(token)));
+        }
+
+        if self._match(vec![TokenType::Continue]) {
+            let token = self.previous();
+            self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
+            return Ok(b!(Continue::new(token)));
+        }
+
+        if self._match(vec![TokenType::Try]) {
+            return self.try_statement();
+        }
+
+        if self._match(vec![TokenType::Throw]) {
+            let token = self.previous();
+            let value = self.expression()?;
+            self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
+            return Ok(b!(Throw::new(token, value)));
+        }
+
         self.expr_statement()
     }
 
@@ -252,13 +723,67 @@ where
         Ok(b!(Return::new(token, expr)))
     }
 
+    fn try_statement(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let keyword = self.previous();
+        self.consume(
+            TokenType::LeftBrace,
+            ParserErrorType::ExpectedLeftBraceAfterTry,
+        )?;
+        let body = self.block_statement()?;
+
+        let catch = if self._match(vec![TokenType::Catch]) {
+            let name = if self._match(vec![TokenType::LeftParen]) {
+                let name =
+                    self.consume(TokenType::Identifier, ParserErrorType::ExpectedVariableName)?;
+                self.consume(
+                    TokenType::RightParen,
+                    ParserErrorType::ExpectedRightParenAfterCatch,
+                )?;
+                Some(name)
+            } else {
+                None
+            };
+            self.consume(
+                TokenType::LeftBrace,
+                ParserErrorType::ExpectedLeftBraceAfterCatch,
+            )?;
+            Some(Catch::new(name, self.block_statement()?))
+        } else {
+            None
+        };
+
+        let finally = if self._match(vec![TokenType::Finally]) {
+            self.consume(
+                TokenType::LeftBrace,
+                ParserErrorType::ExpectedLeftBraceAfterFinally,
+            )?;
+            Some(self.block_statement()?)
+        } else {
+            None
+        };
+
+        if catch.is_none() && finally.is_none() {
+            return Err(
+                ParserError::new(self.peek(), ParserErrorType::ExpectedCatchOrFinally).into(),
+            );
+        }
+
+        Ok(b!(Try::new(keyword, body, catch, finally)))
+    }
+
     fn for_statement(&mut self) -> Result<Box<dyn Stmt<T>>> {
         self.consume(
             TokenType::LeftParen,
             ParserErrorType::ExpectedLeftParenAfterFor,
         )?;
 
-        let initializer: Option<Box<dyn Stmt<T>>> = if self._match(vec![TokenType::Let]) {
+        if self.is_for_in() {
+            return self.for_in_statement();
+        }
+
+        let initializer: Option<Box<dyn Stmt<T>>> = if self._match(vec![TokenType::Semicolon]) {
+            None
+        } else if self._match(vec![TokenType::Let]) {
             Some(self.let_declaration()?)
         } else {
             Some(self.expr_statement()?)
@@ -271,7 +796,7 @@ where
         self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
 
         let mut increment = None;
-        if !self.check(TokenType::Semicolon) {
+        if !self.check(TokenType::RightParen) {
             increment = Some(self.expression()?);
         }
         self.consume(
@@ -279,15 +804,10 @@ where
             ParserErrorType::ExpectedRightParenAfterForStatement,
         )?;
 
-        let mut body = self.statement()?;
+        let body = self.statement()?;
 
-        if let Some(increment) = increment {
-            body = b!(Block::new(vec![body, b!(StmtExpr::new(increment))]));
-        }
-
-        if let Some(condition) = condition {
-            body = b!(While::new(condition, body));
-        }
+        let condition = condition.unwrap_or(b!(Literal::new(Some(Object::Bool(true)))));
+        let mut body: Box<dyn Stmt<T>> = b!(While::with_increment(condition, body, increment));
 
         if let Some(initializer) = initializer {
             body = b!(Block::new(vec![initializer, body]));
@@ -296,6 +816,40 @@ where
         Ok(body)
     }
 
+    /// Looks ahead for `x in` or `key, value in` after `for (`. `in` is only
+    /// a keyword in this position, so it stays usable as a dictionary key.
+    fn is_for_in(&self) -> bool {
+        let is = |offset: usize, ty: TokenType| {
+            self.tokens
+                .get(self.current + offset)
+                .is_some_and(|token| token.get_type() == ty)
+        };
+        let is_in = |offset: usize| {
+            self.tokens.get(self.current + offset).is_some_and(|token| {
+                token.get_type() == TokenType::Identifier && token.get_lexeme() == "in"
+            })
+        };
+        is(0, TokenType::Identifier)
+            && (is_in(1) || (is(1, TokenType::Comma) && is(2, TokenType::Identifier) && is_in(3)))
+    }
+
+    fn for_in_statement(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let mut names = vec![self.advance()];
+        if self._match(vec![TokenType::Comma]) {
+            names.push(self.advance());
+        }
+        let keyword = self.advance();
+        let iterable = self.expression()?;
+        self.consume(
+            TokenType::RightParen,
+            ParserErrorType::ExpectedRightParenAfterForStatement,
+        )?;
+
+        let body = self.statement()?;
+
+        Ok(b!(ForIn::new(names, keyword, iterable, body)))
+    }
+
     fn while_statement(&mut self) -> Result<Box<dyn Stmt<T>>> {
         let condition = self.expression()?;
 
@@ -348,22 +902,63 @@ where
     }
 
     fn assignment(&mut self) -> Result<Box<dyn Expr<T>>> {
-        let expr = self.logic_or()?;
-        if self._match(vec![TokenType::Equal]) {
+        let expr = self.nil_coalescing()?;
+        if self._match(vec![
+            TokenType::Equal,
+            TokenType::PlusEqual,
+            TokenType::MinusEqual,
+            TokenType::StarEqual,
+            TokenType::SlashEqual,
+            TokenType::PercentEqual,
+        ]) {
             let token = self.previous();
             let value = self.assignment()?;
+            let op = match token.get_type() {
+                TokenType::Equal => AssignOp::Plain,
+                _ => AssignOp::Compound(token.clone()),
+            };
 
-            if let Some(expr) = expr.downcast_ref::<Variable>() {
-                let name = expr.get_token();
-                return Ok(b!(Assign::new(name, value)));
-            }
+            return self.assignment_target(expr, token, op, value);
+        }
 
-            if let Some(expr) = expr.downcast_ref::<Get<T>>() {
-                let (ty, obj) = expr.extract();
-                return Ok(b!(Set::new(ty.clone().into(), obj.clone_expr(), value)));
-            }
+        Ok(expr)
+    }
 
-            return Err(ParserError::new(token, ParserErrorType::InvalidAssignmentTarget).into());
+    fn assignment_target(
+        &self,
+        target: Box<dyn Expr<T>>,
+        token: Token,
+        op: AssignOp,
+        value: Box<dyn Expr<T>>,
+    ) -> Result<Box<dyn Expr<T>>> {
+        if let Some(target) = target.downcast_ref::<Variable>() {
+            let name = target.get_token();
+            return Ok(b!(Assign::with_op(name, op, value)));
+        }
+
+        if let Some(target) = target.downcast_ref::<Get<T>>()
+            && !target.is_optional()
+        {
+            let (ty, obj) = target.extract();
+            return Ok(b!(Set::with_op(
+                ty.clone().into(),
+                obj.clone_expr(),
+                op,
+                value
+            )));
+        }
+
+        Err(ParserError::new(token, ParserErrorType::InvalidAssignmentTarget).into())
+    }
+
+    /// `a ?? b`, binding looser than `or`.
+    fn nil_coalescing(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let mut expr = self.logic_or()?;
+
+        while self._match(vec![TokenType::QuestionQuestion]) {
+            let token = self.previous();
+            let right = self.logic_or()?;
+            expr = b!(Logical::new(expr, token, right));
         }
 
         Ok(expr)
@@ -406,7 +1001,7 @@ where
     }
 
     fn comparison(&mut self) -> Result<Box<dyn Expr<T>>> {
-        let mut expr = self.term()?;
+        let mut expr = self.range()?;
 
         while self._match(vec![
             TokenType::Greater,
@@ -414,6 +1009,68 @@ where
             TokenType::Less,
             TokenType::LessEqual,
         ]) {
+            let token = self.previous();
+            let right = self.range()?;
+            expr = b!(Binary::new(expr, token, right));
+        }
+
+        Ok(expr)
+    }
+
+    /// `start..end`, non-associative and binding looser than arithmetic so
+    /// that `0..n - 1` reads as `0..(n - 1)`.
+    fn range(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let mut expr = self.bit_or()?;
+
+        if self._match(vec![TokenType::DotDot]) {
+            let token = self.previous();
+            let right = self.bit_or()?;
+            expr = b!(Binary::new(expr, token, right));
+        }
+
+        Ok(expr)
+    }
+
+    fn bit_or(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let mut expr = self.bit_xor()?;
+
+        while self._match(vec![TokenType::Pipe]) {
+            let token = self.previous();
+            let right = self.bit_xor()?;
+            expr = b!(Binary::new(expr, token, right));
+        }
+
+        Ok(expr)
+    }
+
+    fn bit_xor(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let mut expr = self.bit_and()?;
+
+        while self._match(vec![TokenType::Caret]) {
+            let token = self.previous();
+            let right = self.bit_and()?;
+            expr = b!(Binary::new(expr, token, right));
+        }
+
+        Ok(expr)
+    }
+
+    fn bit_and(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let mut expr = self.shift()?;
+
+        while self._match(vec![TokenType::Ampersand]) {
+            let token = self.previous();
+            let right = self.shift()?;
+            expr = b!(Binary::new(expr, token, right));
+        }
+
+        Ok(expr)
+    }
+
+    fn shift(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let mut expr = self.term()?;
+
+        while self._match(vec![TokenType::LessLess, TokenType::GreaterGreater]) {
             let token = self.previous();
             let right = self.term()?;
             expr = b!(Binary::new(expr, token, right));
@@ -437,7 +1094,7 @@ where
     fn factor(&mut self) -> Result<Box<dyn Expr<T>>> {
         let mut expr = self.unary()?;
 
-        while self._match(vec![TokenType::Star, TokenType::Slash]) {
+        while self._match(vec![TokenType::Star, TokenType::Slash, TokenType::Percent]) {
             let token = self.previous();
             let right = self.unary()?;
             expr = b!(Binary::new(expr, token, right));
@@ -447,20 +1104,64 @@ where
     }
 
     fn unary(&mut self) -> Result<Box<dyn Expr<T>>> {
-        if self._match(vec![TokenType::Bang, TokenType::Minus]) {
+        if self._match(vec![TokenType::PlusPlus, TokenType::MinusMinus]) {
+            let token = self.previous();
+            let target = self.unary()?;
+            let one = b!(Literal::new(Some(Object::Number(1.0))));
+            return self.assignment_target(target, token.clone(), AssignOp::Compound(token), one);
+        }
+
+        if self._match(vec![TokenType::Bang, TokenType::Minus, TokenType::Tilde]) {
             let token = self.previous();
-            let right = self.call()?;
+            let right = self.power()?;
             return Ok(b!(Unary::new(token, right)));
         }
 
-        self.call()
+        self.power()
     }
 
+    fn power(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let expr = self.postfix()?;
+
+        if self._match(vec![TokenType::StarStar]) {
+            let token = self.previous();
+            let right = self.unary()?;
+            return Ok(b!(Binary::new(expr, token, right)));
+        }
+
+        Ok(expr)
+    }
+
+    fn postfix(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let expr = self.call()?;
+
+        if self._match(vec![TokenType::PlusPlus, TokenType::MinusMinus]) {
+            let token = self.previous();
+            let one = b!(Literal::new(Some(Object::Number(1.0))));
+            return self.assignment_target(expr, token.clone(), AssignOp::Postfix(token), one);
+        }
+
+        Ok(expr)
+    }
+
+    /// Calls, property accesses and indexing, each optionally guarded with
+    /// `?.`, `?.()` or `?[`. A guard that hits nil skips the rest of the
+    /// chain, so `a?.b.c` is nil when `a` is, but still fails when `a.b` is.
     fn call(&mut self) -> Result<Box<dyn Expr<T>>> {
         let mut expr = self.primary()?;
         loop {
             if self._match(vec![TokenType::LeftParen]) {
-                expr = self.finish_call(expr)?;
+                expr = b!(self.finish_call(expr)?);
+            } else if self._match(vec![TokenType::QuestionDot]) {
+                if self._match(vec![TokenType::LeftParen]) {
+                    expr = b!(self.finish_call(expr)?.optional());
+                } else {
+                    let name = self.consume(
+                        TokenType::Identifier,
+                        ParserErrorType::ExpectedPropertyAfterDot,
+                    )?;
+                    expr = b!(Get::new(GetType::Name(name), expr).optional())
+                }
             } else if self._match(vec![TokenType::Dot]) {
                 let name = self.consume(
                     TokenType::Identifier,
@@ -468,12 +1169,16 @@ where
                 )?;
                 expr = b!(Get::new(GetType::Name(name), expr))
             } else if self._match(vec![TokenType::LeftBracket]) {
-                let index = self.expression()?;
-                let token = self.consume(
-                    TokenType::RightBracket,
-                    ParserErrorType::ExpectedRightBracket,
-                )?;
-                expr = b!(Get::new(GetType::Index(token, index), expr))
+                expr = b!(self.finish_index(expr)?)
+            } else if self.check(TokenType::Question)
+                && self
+                    .tokens
+                    .get(self.current + 1)
+                    .is_some_and(|token| token.get_type() == TokenType::LeftBracket)
+            {
+                self.advance();
+                self.advance();
+                expr = b!(self.finish_index(expr)?.optional())
             } else {
                 break;
             }
@@ -482,12 +1187,41 @@ where
         Ok(expr)
     }
 
-    fn finish_call(&mut self, expr: Box<dyn Expr<T>>) -> Result<Box<dyn Expr<T>>> {
+    fn finish_index(&mut self, expr: Box<dyn Expr<T>>) -> Result<Get<T>> {
+        let index = self.expression()?;
+        let token = self.consume(
+            TokenType::RightBracket,
+            ParserErrorType::ExpectedRightBracket,
+        )?;
+        Ok(Get::new(GetType::Index(token, index), expr))
+    }
+
+    fn finish_call(&mut self, expr: Box<dyn Expr<T>>) -> Result<Call<T>> {
         let mut arguments = vec![];
+        let mut named = vec![];
         if !self.check(TokenType::RightParen) {
-            arguments.push(self.expression()?);
-            while self._match(vec![TokenType::Comma]) {
-                arguments.push(self.expression()?);
+            loop {
+                let is_named = self.check(TokenType::Identifier)
+                    && self
+                        .tokens
+                        .get(self.current + 1)
+                        .is_some_and(|token| token.get_type() == TokenType::Colon);
+                if is_named {
+                    let name = self.advance();
+                    self.advance();
+                    named.push((name, self.expression()?));
+                } else if !named.is_empty() {
+                    return Err(ParserError::new(
+                        self.peek(),
+                        ParserErrorType::PositionalArgAfterNamed,
+                    )
+                    .into());
+                } else {
+                    arguments.push(self.expression()?);
+                }
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
+                }
             }
         }
 
@@ -496,11 +1230,11 @@ where
             ParserErrorType::ExpectedRightParenAfterArguments,
         )?;
 
-        if arguments.len() > 255 {
+        if arguments.len() + named.len() > 255 {
             return Err(ParserError::new(paren, ParserErrorType::CountOfArgsGreaterThen255).into());
         }
 
-        Ok(b!(Call::new(expr, paren, arguments)))
+        Ok(Call::with_named(expr, paren, arguments, named))
     }
 
     fn primary(&mut self) -> Result<Box<dyn Expr<T>>> {
@@ -518,6 +1252,10 @@ where
             return Ok(b!(Literal::new(self.previous().get_lit())));
         }
 
+        if self._match(vec![TokenType::Interpolation]) {
+            return self.interpolation();
+        }
+
         if self._match(vec![TokenType::Identifier]) {
             return Ok(b!(Variable::new(self.previous())));
         }
@@ -558,78 +1296,227 @@ where
             return self.anon_fun();
         }
 
+        if self._match(vec![TokenType::Match]) {
+            return self.match_expr();
+        }
+
         Err(self
             .error(self.peek(), ParserErrorType::ExpectedExpression)
             .into())
     }
 
-    fn anon_fun(&mut self) -> Result<Box<dyn Expr<T>>> {
-        let name = self.previous();
-
+    fn match_expr(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let keyword = self.previous();
+        let subject = self.expression()?;
         self.consume(
-            TokenType::LeftParen,
-            ParserErrorType::ExpectedLeftParenAfterFunIdent,
+            TokenType::LeftBrace,
+            ParserErrorType::ExpectedLeftBraceAfterMatch,
         )?;
 
-        let mut params = vec![];
+        let mut arms = vec![];
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            let pattern = self.pattern()?;
+            let guard = if self._match(vec![TokenType::If]) {
+                Some(self.expression()?)
+            } else {
+                None
+            };
+            self.consume(
+                TokenType::FatArrow,
+                ParserErrorType::ExpectedFatArrowAfterPattern,
+            )?;
 
-        if !self.check(TokenType::RightParen) {
-            let param = self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?;
-            params.push(param);
-            while self.check(TokenType::Comma) {
-                if params.len() >= 255 {
-                    return Err(ParserError::new(
-                        self.peek(),
-                        ParserErrorType::CountOfParamsGreaterThen255,
-                    )
-                    .into());
+            let body = if self.check(TokenType::LeftBrace) && !self.is_obj_ahead() {
+                self.advance();
+                ArmBody::Block(self.block_statement()?)
+            } else {
+                ArmBody::Expr(self.expression()?)
+            };
+            let is_block = matches!(body, ArmBody::Block(_));
+            arms.push(MatchArm::new(pattern, guard, body));
+
+            if !self._match(vec![TokenType::Comma]) && !is_block {
+                break;
+            }
+        }
+        self.consume(TokenType::RightBrace, ParserErrorType::ExpectedRightBrace)?;
+
+        Ok(b!(Match::new(keyword, subject, arms)))
+    }
+
+    /// Tells a dictionary literal (`{}` or `{key: ...`) from a block when the
+    /// current token is `{`.
+    fn is_obj_ahead(&self) -> bool {
+        let is = |offset: usize, types: &[TokenType]| {
+            self.tokens
+                .get(self.current + offset)
+                .is_some_and(|token| types.contains(&token.get_type()))
+        };
+        is(1, &[TokenType::RightBrace])
+            || (is(
+                1,
+                &[TokenType::Identifier, TokenType::String, TokenType::Number],
+            ) && is(2, &[TokenType::Colon]))
+    }
+
+    pub fn pattern(&mut self) -> Result<Pattern> {
+        if self._match(vec![TokenType::Identifier]) {
+            let name = self.previous();
+            if self._match(vec![TokenType::Dot]) {
+                return self.variant_pattern(name);
+            }
+            return Ok(match name.get_lexeme() {
+                "_" => Pattern::Wildcard(name),
+                ty if TYPE_PATTERNS.contains(&ty) => Pattern::Type(name),
+                _ => Pattern::Binding(name),
+            });
+        }
+
+        if self._match(vec![TokenType::Number, TokenType::String]) {
+            let token = self.previous();
+            let value = token.get_lit().unwrap_or_default();
+            return Ok(Pattern::Literal(token, value));
+        }
+        if self._match(vec![TokenType::True, TokenType::False, TokenType::Nil]) {
+            let token = self.previous();
+            let value = match token.get_type() {
+                TokenType::True => Object::Bool(true),
+                TokenType::False => Object::Bool(false),
+                _ => Object::Nil,
+            };
+            return Ok(Pattern::Literal(token, value));
+        }
+        if self._match(vec![TokenType::Minus]) {
+            let token = self.consume(TokenType::Number, ParserErrorType::ExpectedPattern)?;
+            let value = (-token.get_lit().unwrap_or_default())?;
+            return Ok(Pattern::Literal(token, value));
+        }
+
+        if self._match(vec![TokenType::LeftBracket]) {
+            let bracket = self.previous();
+            let mut items = vec![];
+            let mut rest = None;
+            while !self.check(TokenType::RightBracket) && !self.is_at_end() {
+                if self._match(vec![TokenType::DotDot]) {
+                    let name = if self._match(vec![TokenType::Identifier]) {
+                        Some(self.previous())
+                    } else {
+                        None
+                    };
+                    rest = Some(Rest(name));
+                    break;
+                }
+                items.push(self.pattern()?);
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+            let error = if rest.is_some() {
+                ParserErrorType::ExpectedRightBracketAfterRest
+            } else {
+                ParserErrorType::ExpectedRightBracket
+            };
+            self.consume(TokenType::RightBracket, error)?;
+            return Ok(Pattern::List(bracket, items, rest));
+        }
+
+        if self._match(vec![TokenType::LeftBrace]) {
+            let brace = self.previous();
+            let mut entries = vec![];
+            while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+                let shorthand = self.check(TokenType::Identifier)
+                    && self
+                        .tokens
+                        .get(self.current + 1)
+                        .is_none_or(|token| token.get_type() != TokenType::Colon);
+                if shorthand {
+                    let name = self.advance();
+                    entries.push((name.clone(), Pattern::Binding(name)));
+                } else {
+                    let key = self.obj_key()?;
+                    self.consume(TokenType::Colon, ParserErrorType::ExpectedColon)?;
+                    entries.push((key, self.pattern()?));
+                }
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
                 }
-                self.consume(TokenType::Comma, ParserErrorType::ExpectedComma)?;
-                let param =
-                    self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?;
-                params.push(param);
             }
+            self.consume(TokenType::RightBrace, ParserErrorType::ExpectedRightBrace)?;
+            return Ok(Pattern::Dictionary(brace, entries));
         }
 
+        Err(self
+            .error(self.peek(), ParserErrorType::ExpectedPattern)
+            .into())
+    }
+
+    /// `Enum.Variant` or `Enum.Variant(patterns..)`, after the '.'.
+    fn variant_pattern(&mut self, enum_name: Token) -> Result<Pattern> {
+        let name = self.consume(TokenType::Identifier, ParserErrorType::ExpectedVariantName)?;
+        if !self._match(vec![TokenType::LeftParen]) {
+            return Ok(Pattern::Variant(name, enum_name, None));
+        }
+
+        let mut items = vec![];
+        while !self.check(TokenType::RightParen) && !self.is_at_end() {
+            items.push(self.pattern()?);
+            if !self._match(vec![TokenType::Comma]) {
+                break;
+            }
+        }
         self.consume(
             TokenType::RightParen,
-            ParserErrorType::ExpectedRightParenAfterParams,
+            ParserErrorType::ExpectedMatchingParens,
         )?;
+        Ok(Pattern::Variant(name, enum_name, Some(items)))
+    }
+
+    fn interpolation(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let token = self.previous();
+        let mut parts: Vec<Box<dyn Expr<T>>> = vec![];
+        loop {
+            parts.push(b!(Literal::new(self.previous().get_lit())));
+            parts.push(self.expression()?);
+            if !self._match(vec![TokenType::InterpolationPart]) {
+                break;
+            }
+        }
+        let end = self.consume(
+            TokenType::InterpolationEnd,
+            ParserErrorType::ExpectedRightBraceAfterInterpolation,
+        )?;
+        parts.push(b!(Literal::new(end.get_lit())));
+
+        Ok(b!(Interpolation::new(token, parts)))
+    }
+
+    fn anon_fun(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let name = self.previous();
+
+        self.consume(
+            TokenType::LeftParen,
+            ParserErrorType::ExpectedLeftParenAfterFunIdent,
+        )?;
+
+        let (params, mut body) = self.parameters()?;
         self.consume(
             TokenType::LeftBrace,
             ParserErrorType::ExpectedLeftBraceBeforeBody,
         )?;
 
-        let body = self.block_statement()?;
+        body.extend(self.block_statement()?);
         Ok(b!(AnonFun::new(name, params, body)))
     }
 
     fn obj(&mut self) -> Result<Box<dyn Expr<T>>> {
         let mut values = HashMap::new();
         if !self.check(TokenType::RightBrace) {
-            let name = if self._match(vec![
-                TokenType::Identifier,
-                TokenType::Number,
-                TokenType::String,
-            ]) {
-                self.previous()
-            } else {
-                return Err(ParserError::new(self.peek(), ParserErrorType::ExpectedKey).into());
-            };
-            //println!("{name}");
+            let name = self.obj_key()?;
             self.consume(TokenType::Colon, ParserErrorType::ExpectedColon)?;
             let value = self.expression()?;
             values.insert(name, value);
             while self._match(vec![TokenType::Comma]) {
-                let name = if self._match(vec![
-                    TokenType::Identifier,
-                    TokenType::Number,
-                    TokenType::String,
-                ]) {
-                    self.previous()
-                } else {
-                    return Err(ParserError::new(self.peek(), ParserErrorType::ExpectedKey).into());
-                };
+                let name = self.obj_key()?;
                 self.consume(TokenType::Colon, ParserErrorType::ExpectedColon)?;
                 let value = self.expression()?;
                 values.insert(name, value);
@@ -639,6 +1526,31 @@ where
         Ok(b!(Obj::new(values)))
     }
 
+    /// Parses a dictionary key. String and number keys are normalised to the
+    /// same text they are looked up by at runtime, so `{"a b": 1}` is read
+    /// back with `dict["a b"]` and `{1: x}` with `dict[1]`.
+    fn obj_key(&mut self) -> Result<Token> {
+        if !self._match(vec![
+            TokenType::Identifier,
+            TokenType::Number,
+            TokenType::String,
+        ]) {
+            return Err(ParserError::new(self.peek(), ParserErrorType::ExpectedKey).into());
+        }
+        let token = self.previous();
+        let key = match token.get_type() {
+            TokenType::Identifier => token.get_lexeme().to_string(),
+            _ => token.get_lit().unwrap_or_default().to_string(),
+        };
+        Ok(Token::new(
+            TokenType::String,
+            &key,
+            Some(Object::String(key.clone())),
+            token.get_line(),
+            token.get_pos_in_line(),
+        ))
+    }
+
     fn list(&mut self) -> Result<Box<dyn Expr<T>>> {
         let mut values = vec![];
         if !self.check(TokenType::RightBracket) {
@@ -710,13 +1622,20 @@ where
 
             match self.peek().get_type() {
                 TokenType::Class
+                | TokenType::Trait
+                | TokenType::Enum
                 | TokenType::Fun
                 | TokenType::Let
+                | TokenType::Const
                 | TokenType::For
                 | TokenType::If
                 | TokenType::While
                 | TokenType::Print
-                | TokenType::Return => return,
+                | TokenType::Return
+                | TokenType::Break
+                | TokenType::Continue
+                | TokenType::Try
+                | TokenType::Throw => return,
                 _ => {}
             }
 
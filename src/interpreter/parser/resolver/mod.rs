@@ -5,9 +5,11 @@ use crate::interpreter::ast::expr::binary::Binary;
 use crate::interpreter::ast::expr::call::Call;
 use crate::interpreter::ast::expr::get::{Get, GetType};
 use crate::interpreter::ast::expr::grouping::Grouping;
+use crate::interpreter::ast::expr::interpolation::Interpolation;
 use crate::interpreter::ast::expr::list::List;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::expr::logical::Logical;
+use crate::interpreter::ast::expr::match_expr::{ArmBody, Match};
 use crate::interpreter::ast::expr::object::Obj;
 use crate::interpreter::ast::expr::self_expr::SelfExpr;
 use crate::interpreter::ast::expr::set::{Set, SetType};
@@ -15,23 +17,32 @@ use crate::interpreter::ast::expr::superclass::Super;
 use crate::interpreter::ast::expr::unary::Unary;
 use crate::interpreter::ast::expr::variable::Variable;
 use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::ast::pattern::Pattern;
 use crate::interpreter::ast::stmt::block::Block;
+use crate::interpreter::ast::stmt::break_stmt::Break;
 use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::continue_stmt::Continue;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
+use crate::interpreter::ast::stmt::for_in_stmt::ForIn;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::ast::stmt::if_stmt::If;
 use crate::interpreter::ast::stmt::let_stmt::Let;
 use crate::interpreter::ast::stmt::print::Print;
 use crate::interpreter::ast::stmt::return_stmt::Return;
 use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
-use crate::interpreter::ast::stmt::use_stmt::Use;
+use crate::interpreter::ast::stmt::throw_stmt::Throw;
+use crate::interpreter::ast::stmt::trait_stmt::Trait;
+use crate::interpreter::ast::stmt::try_stmt::Try;
+use crate::interpreter::ast::stmt::use_stmt::{Import, Use};
 use crate::interpreter::ast::stmt::while_stmt::While;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
 use crate::interpreter::error::Result;
-use crate::interpreter::object::Object;
+use crate::interpreter::exporter::Exporter;
+use crate::interpreter::object::{Object, enumeration};
 use crate::interpreter::parser::error::{ParserError, ParserErrorType};
 use crate::interpreter::scanner::token::Token;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 
 #[derive(Clone, Copy, PartialEq)]
@@ -49,16 +60,56 @@ pub enum ClassType {
     None,
 }
 
+#[derive(Clone, Copy, PartialEq)]
+pub enum LoopType {
+    Loop,
+    None,
+}
+
 pub enum SomeFun {
     Fun(Fun<Result<Object>>),
     AnonFun(AnonFun<Result<Object>>),
 }
 
+/// Methods a trait provides and the ones it requires from the classes
+/// including it.
+struct TraitInfo {
+    provided: Vec<String>,
+    required: Vec<String>,
+}
+
+/// Traits, classes and enums declared in a scope, for checking that classes
+/// define the methods their traits require and that patterns name real
+/// variants.
+#[derive(Default)]
+struct TypeScope {
+    traits: HashMap<String, TraitInfo>,
+    /// Every method name a declared class has, including inherited and
+    /// mixed in ones, or `None` if that can't be known before running.
+    classes: HashMap<String, Option<HashSet<String>>>,
+    /// Variant names of each enum.
+    enums: HashMap<String, Vec<String>>,
+}
+
 pub struct Resolver<'a> {
     interpreter: &'a mut Interpreter,
     stack: Vec<HashMap<String, bool>>,
+    /// Names declared with `const`, per scope in `stack`.
+    consts: Vec<HashSet<String>>,
+    global_consts: HashSet<String>,
     current_function: FunctionType,
     current_class: ClassType,
+    current_loop: LoopType,
+    /// Traits, classes and enums per scope in `stack`.
+    types: Vec<TypeScope>,
+    global_types: TypeScope,
+    /// Names bound by namespaced and selective imports of the file.
+    imported: HashSet<String>,
+    /// Names declared at the top level of the file, including the ones
+    /// spliced in by plain imports.
+    top_level: HashSet<String>,
+    /// Length of `stack` at the top level of the file.
+    file_depth: usize,
 }
 
 impl<'a> Resolver<'a>
@@ -69,8 +120,25 @@ where
         Self {
             interpreter,
             stack: vec![],
+            consts: vec![],
+            global_consts: HashSet::new(),
             current_function: FunctionType::None,
             current_class: ClassType::None,
+            current_loop: LoopType::None,
+            types: vec![],
+            global_types: TypeScope {
+                enums: enumeration::Enum::engine()
+                    .iter()
+                    .map(|value| {
+                        let variants = value.variant_names().map(String::from).collect();
+                        (value.get_name().to_string(), variants)
+                    })
+                    .collect(),
+                ..Default::default()
+            },
+            imported: HashSet::new(),
+            top_level: HashSet::new(),
+            file_depth: 0,
         }
     }
 
@@ -89,21 +157,66 @@ where
         expr.accept(self)
     }
 
+    /// Resolves an imported file in a scope of its own below the globals,
+    /// the way the interpreter runs it, and gives the types it declares.
+    fn resolve_module(&mut self, module: &[Box<dyn Stmt<Result<Object>>>]) -> Result<TypeScope> {
+        let stack = std::mem::take(&mut self.stack);
+        let consts = std::mem::take(&mut self.consts);
+        let types = std::mem::take(&mut self.types);
+        let imported = std::mem::take(&mut self.imported);
+        let top_level = std::mem::take(&mut self.top_level);
+        let file_depth = std::mem::replace(&mut self.file_depth, 1);
+        self.begin_scope();
+        let res = self.resolve(module.iter().map(AsRef::as_ref).collect());
+        self.file_depth = file_depth;
+        self.top_level = top_level;
+        self.stack = stack;
+        self.consts = consts;
+        let module_types = std::mem::replace(&mut self.types, types).pop();
+        self.imported = imported;
+        res.map(|_| module_types.unwrap_or_default())
+    }
+
     fn begin_scope(&mut self) {
         self.stack.push(HashMap::new());
+        self.consts.push(HashSet::new());
+        self.types.push(TypeScope::default());
     }
 
     fn end_scope(&mut self) {
         self.stack.pop();
+        self.consts.pop();
+        self.types.pop();
     }
 
-    fn declare(&mut self, name: &Token) {
+    /// Declares `name` in the innermost scope, rejecting a top-level
+    /// declaration of a name the file imports.
+    fn declare(&mut self, name: &Token) -> Result<()> {
+        if self.stack.len() == self.file_depth {
+            if self.imported.contains(name.get_lexeme()) {
+                return Err(ParserError::new(
+                    name.clone(),
+                    ParserErrorType::ImportCollision(name.get_lexeme().into()),
+                )
+                .into());
+            }
+            self.top_level.insert(name.get_lexeme().to_string());
+        }
+        match self.consts.last_mut() {
+            Some(consts) => consts.remove(name.get_lexeme()),
+            None => self.global_consts.remove(name.get_lexeme()),
+        };
+        let types = self.current_types();
+        types.traits.remove(name.get_lexeme());
+        types.classes.remove(name.get_lexeme());
+        types.enums.remove(name.get_lexeme());
         if self.stack.is_empty() {
-            return;
+            return Ok(());
         }
 
         let scope = self.stack.last_mut().unwrap();
         scope.insert(name.get_lexeme().to_string(), false);
+        Ok(())
     }
 
     fn define(&mut self, name: &Token) {
@@ -115,6 +228,106 @@ where
         scope.insert(name.get_lexeme().to_string(), true);
     }
 
+    fn mark_const(&mut self, name: &Token) {
+        let name = name.get_lexeme().to_string();
+        match self.consts.last_mut() {
+            Some(consts) => consts.insert(name),
+            None => self.global_consts.insert(name),
+        };
+    }
+
+    /// Rejects assigning to a name whose nearest declaration is `const`.
+    fn check_assignable(&self, name: &Token) -> Result<()> {
+        let is_const = match self
+            .stack
+            .iter()
+            .rposition(|scope| scope.contains_key(name.get_lexeme()))
+        {
+            Some(i) => self.consts[i].contains(name.get_lexeme()),
+            None => self.global_consts.contains(name.get_lexeme()),
+        };
+        if is_const {
+            return Err(ParserError::new(
+                name.clone(),
+                ParserErrorType::CantAssignToConst(name.get_lexeme().into()),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// The traits and classes of the scope `name` is declared in, the
+    /// globals if it isn't declared in a local scope.
+    fn types_of(&self, name: &Token) -> &TypeScope {
+        match self
+            .stack
+            .iter()
+            .rposition(|scope| scope.contains_key(name.get_lexeme()))
+        {
+            Some(i) => &self.types[i],
+            None => &self.global_types,
+        }
+    }
+
+    /// The scope new traits and classes are recorded in.
+    fn current_types(&mut self) -> &mut TypeScope {
+        self.types.last_mut().unwrap_or(&mut self.global_types)
+    }
+
+    /// Declares and defines every name bound by `pattern`, rejecting names
+    /// bound twice and variants their enum doesn't have.
+    fn define_pattern(&mut self, pattern: &Pattern) -> Result<()> {
+        self.check_variants(pattern)?;
+        let names = pattern.bindings();
+        for (i, name) in names.iter().enumerate() {
+            if names[..i]
+                .iter()
+                .any(|prev| prev.get_lexeme() == name.get_lexeme())
+            {
+                return Err(ParserError::new(
+                    (*name).clone(),
+                    ParserErrorType::DuplicateBinding(name.get_lexeme().into()),
+                )
+                .into());
+            }
+            self.declare(name)?;
+            self.define(name);
+        }
+        Ok(())
+    }
+
+    /// Rejects variant patterns naming a variant their enum doesn't declare.
+    /// Enums the resolver doesn't know the variants of are not checked.
+    fn check_variants(&self, pattern: &Pattern) -> Result<()> {
+        match pattern {
+            Pattern::List(_, items, _) => {
+                items.iter().try_for_each(|item| self.check_variants(item))
+            }
+            Pattern::Dictionary(_, entries) => entries
+                .iter()
+                .try_for_each(|(_, item)| self.check_variants(item)),
+            Pattern::Variant(name, enum_name, items) => {
+                if let Some(variants) = self.types_of(enum_name).enums.get(enum_name.get_lexeme())
+                    && !variants.iter().any(|variant| variant == name.get_lexeme())
+                {
+                    return Err(ParserError::new(
+                        name.clone(),
+                        ParserErrorType::UndefinedVariant(
+                            enum_name.get_lexeme().into(),
+                            name.get_lexeme().into(),
+                        ),
+                    )
+                    .into());
+                }
+                items
+                    .iter()
+                    .flatten()
+                    .try_for_each(|item| self.check_variants(item))
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn resolve_local(&mut self, expr: &dyn Expr<Result<Object>>, name: &Token) {
         if self.stack.is_empty() {
             return;
@@ -129,9 +342,72 @@ where
         }
     }
 
+    /// Records the methods `class` ends up with and reports the first one
+    /// required by an included trait but missing. Classes whose superclass
+    /// or traits were not declared in the script are not checked.
+    fn check_trait_requirements(&mut self, class: &Class<Result<Object>>) -> Result<Object> {
+        let (name, methods, super_class) = class.extract();
+        let mut provided: Option<HashSet<String>> = Some(
+            methods
+                .iter()
+                .map(|method| method.get_name().get_lexeme().to_string())
+                .collect(),
+        );
+
+        if let Some(super_class) = super_class {
+            let super_name = super_class.get_token();
+            let inherited = self
+                .types_of(&super_name)
+                .classes
+                .get(super_name.get_lexeme())
+                .cloned()
+                .flatten();
+            provided = provided
+                .zip(inherited)
+                .map(|(own, inherited)| &own | &inherited);
+        }
+
+        let mut required = vec![];
+        for mixin in class.get_mixins() {
+            let mixin = mixin.get_token();
+            match self.types_of(&mixin).traits.get(mixin.get_lexeme()) {
+                Some(info) => {
+                    if let Some(provided) = provided.as_mut() {
+                        provided.extend(info.provided.iter().cloned());
+                    }
+                    required.extend(
+                        info.required
+                            .iter()
+                            .map(|method| (mixin.get_lexeme().to_string(), method.clone())),
+                    );
+                }
+                None => provided = None,
+            }
+        }
+
+        if let Some(provided) = &provided
+            && let Some((mixin, method)) = required
+                .into_iter()
+                .find(|(_, method)| !provided.contains(method))
+        {
+            return Err(ParserError::new(
+                name.clone(),
+                ParserErrorType::MissingTraitMethod(mixin, method),
+            )
+            .into());
+        }
+
+        self.current_types()
+            .classes
+            .insert(name.get_lexeme().to_string(), provided);
+        Ok(Object::Nil)
+    }
+
     fn resolve_function(&mut self, func: SomeFun, ty: FunctionType) -> Result<()> {
         let enclosing_func = self.current_function;
         self.current_function = ty;
+        let enclosing_loop = self.current_loop;
+        self.current_loop = LoopType::None;
 
         self.begin_scope();
         let (params, body) = match func {
@@ -149,13 +425,17 @@ where
         };
 
         for param in params {
-            self.declare(&param);
-            self.define(&param);
+            self.declare(param.get_name())?;
+            if let Some(default) = param.get_default() {
+                self.resolve_expr(default)?;
+            }
+            self.define(param.get_name());
         }
         self.resolve(body.iter().map(AsRef::as_ref).collect())?;
         self.end_scope();
 
         self.current_function = enclosing_func;
+        self.current_loop = enclosing_loop;
         Ok(())
     }
 }
@@ -201,6 +481,7 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
     }
 
     fn visit_assign(&mut self, assign: &Assign<Result<Object>>) -> Result<Object> {
+        self.check_assignable(&assign.get_token())?;
         self.resolve_expr(assign.get_value())?;
         self.resolve_local(assign, &assign.get_token());
         Ok(Object::Nil)
@@ -217,6 +498,9 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
         for arg in call.get_args() {
             self.resolve_expr(arg)?;
         }
+        for (_, arg) in call.get_named() {
+            self.resolve_expr(arg)?;
+        }
         Ok(Object::Nil)
     }
 
@@ -233,7 +517,7 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
     }
 
     fn visit_set(&mut self, set: &Set<Result<Object>>) -> Result<Object> {
-        let (ty, obj, value) = set.extract();
+        let (ty, obj, _, value) = set.extract();
         match ty {
             SetType::Index(_, index) => {
                 self.resolve_expr(index.clone().deref())?;
@@ -285,10 +569,8 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
 
     fn visit_object(&mut self, object: &Obj<Result<Object>>) -> Result<Object> {
         let values = object.extract();
-        for (key, value) in values.clone() {
-            self.declare(&key);
+        for value in values.values() {
             self.resolve_expr(value.deref())?;
-            self.define(&key);
         }
         Ok(Object::Nil)
     }
@@ -297,6 +579,52 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
         self.resolve_function(SomeFun::AnonFun(anon.clone()), FunctionType::Function)?;
         Ok(Object::Nil)
     }
+
+    fn visit_interpolation(
+        &mut self,
+        interpolation: &Interpolation<Result<Object>>,
+    ) -> Result<Object> {
+        for part in interpolation.get_parts() {
+            self.resolve_expr(part)?;
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_match(&mut self, match_expr: &Match<Result<Object>>) -> Result<Object> {
+        self.resolve_expr(match_expr.get_subject())?;
+
+        let arms = match_expr.get_arms();
+        for (i, arm) in arms.iter().enumerate() {
+            let pattern = arm.get_pattern();
+            if arms[..i]
+                .iter()
+                .any(|prev| prev.get_guard().is_none() && prev.get_pattern().covers(pattern))
+            {
+                return Err(ParserError::new(
+                    pattern.get_token().clone(),
+                    ParserErrorType::UnreachableMatchArm,
+                )
+                .into());
+            }
+
+            self.begin_scope();
+            self.define_pattern(pattern)?;
+            if let Some(guard) = arm.get_guard() {
+                self.resolve_expr(guard)?;
+            }
+            match arm.get_body() {
+                ArmBody::Expr(expr) => self.resolve_expr(expr.deref())?,
+                ArmBody::Block(stmts) => {
+                    self.begin_scope();
+                    self.resolve(stmts.iter().map(AsRef::as_ref).collect())?;
+                    self.end_scope();
+                    Object::Nil
+                }
+            };
+            self.end_scope();
+        }
+        Ok(Object::Nil)
+    }
 }
 
 impl StmtVisitor<Result<Object>> for Resolver<'_> {
@@ -311,12 +639,28 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
     }
 
     fn visit_let(&mut self, stmt: &Let<Result<Object>>) -> Result<Object> {
+        if let Some(pattern) = stmt.get_pattern() {
+            if let Some(initializer) = stmt.get_initializer() {
+                self.resolve_expr(initializer)?;
+            }
+            self.define_pattern(pattern)?;
+            if stmt.is_const() {
+                for name in pattern.bindings() {
+                    self.mark_const(name);
+                }
+            }
+            return Ok(Object::Nil);
+        }
+
         let name = stmt.get_ident();
-        self.declare(&name);
+        self.declare(&name)?;
         if let Some(initializer) = stmt.get_initializer() {
             self.resolve_expr(initializer)?;
         }
         self.define(&name);
+        if stmt.is_const() {
+            self.mark_const(&name);
+        }
         Ok(Object::Nil)
     }
 
@@ -338,15 +682,40 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
     }
 
     fn visit_while(&mut self, stmt: &While<Result<Object>>) -> Result<Object> {
-        let (cond, body) = stmt.extract();
+        let (cond, body, increment) = stmt.extract();
         self.resolve_expr(cond)?;
+
+        let enclosing_loop = self.current_loop;
+        self.current_loop = LoopType::Loop;
+        self.resolve_stmt(body)?;
+        self.current_loop = enclosing_loop;
+
+        if let Some(increment) = increment {
+            self.resolve_expr(increment)?;
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_for_in(&mut self, stmt: &ForIn<Result<Object>>) -> Result<Object> {
+        let (names, _, iterable, body) = stmt.extract();
+        self.resolve_expr(iterable)?;
+
+        self.begin_scope();
+        for name in names {
+            self.declare(name)?;
+            self.define(name);
+        }
+        let enclosing_loop = self.current_loop;
+        self.current_loop = LoopType::Loop;
         self.resolve_stmt(body)?;
+        self.current_loop = enclosing_loop;
+        self.end_scope();
         Ok(Object::Nil)
     }
 
     fn visit_fun(&mut self, stmt: &Fun<Result<Object>>) -> Result<Object> {
         let name = stmt.get_name();
-        self.declare(&name);
+        self.declare(&name)?;
         self.define(&name);
         self.resolve_function(SomeFun::Fun(stmt.clone()), FunctionType::Function)?;
         Ok(Object::Nil)
@@ -381,8 +750,8 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
         let enclosing_ty = self.current_class;
         self.current_class = ClassType::Class;
 
+        self.declare(name)?;
         self.define(name);
-        self.declare(name);
 
         if let Some(super_class) = super_class {
             let s_name = super_class.get_token();
@@ -397,6 +766,10 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
             self.stack.last_mut().unwrap().insert("super".into(), true);
         }
 
+        for mixin in class.get_mixins() {
+            self.resolve_expr(mixin)?;
+        }
+
         self.begin_scope();
 
         self.stack.last_mut().unwrap().insert("self".into(), true);
@@ -411,6 +784,17 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
             self.resolve_function(SomeFun::Fun(method.clone()), ty)?
         }
 
+        let members = class
+            .get_statics()
+            .iter()
+            .chain(class.get_getters())
+            .chain(class.get_setters())
+            .chain(class.get_fields())
+            .chain(class.get_static_fields());
+        for member in members {
+            self.resolve_function(SomeFun::Fun(member.clone()), FunctionType::Method)?
+        }
+
         self.end_scope();
 
         if super_class.is_some() {
@@ -419,16 +803,175 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
 
         self.current_class = enclosing_ty;
 
+        self.check_trait_requirements(class)
+    }
+
+    fn visit_enum(&mut self, stmt: &Enum) -> Result<Object> {
+        let (name, variants) = stmt.extract();
+        self.declare(name)?;
+        self.define(name);
+        self.current_types().enums.insert(
+            name.get_lexeme().to_string(),
+            variants
+                .iter()
+                .map(|(variant, _)| variant.get_lexeme().to_string())
+                .collect(),
+        );
+        Ok(Object::Nil)
+    }
+
+    fn visit_trait(&mut self, stmt: &Trait<Result<Object>>) -> Result<Object> {
+        let (name, methods, required) = stmt.extract();
+        let enclosing_ty = self.current_class;
+        self.current_class = ClassType::Class;
+
+        self.declare(name)?;
+        self.define(name);
+
+        self.begin_scope();
+        self.stack.last_mut().unwrap().insert("self".into(), true);
+        for method in methods {
+            self.resolve_function(SomeFun::Fun(method.clone()), FunctionType::Method)?
+        }
+        self.end_scope();
+
+        self.current_class = enclosing_ty;
+
+        self.current_types().traits.insert(
+            name.get_lexeme().to_string(),
+            TraitInfo {
+                provided: methods
+                    .iter()
+                    .map(|method| method.get_name().get_lexeme().to_string())
+                    .collect(),
+                required: required
+                    .iter()
+                    .map(|method| method.get_lexeme().to_string())
+                    .collect(),
+            },
+        );
         Ok(Object::Nil)
     }
 
     fn visit_export(&mut self, class: &Export<Result<Object>>) -> Result<Object> {
         let (_, stmt) = class.extract();
-        self.resolve_stmt(stmt)
+        if let Some(stmt) = stmt {
+            return self.resolve_stmt(stmt);
+        }
+        for (binding, name) in class.get_names() {
+            self.resolve_expr(binding)?;
+            if name.get_lexeme() != binding.get_token().get_lexeme() {
+                self.declare(name)?;
+                self.define(name);
+            }
+        }
+        Ok(Object::Nil)
     }
 
-    fn visit_use(&mut self, _stmt: &Use<Result<Object>>) -> Result<Object> {
-        let (_, expr) = _stmt.extract();
-        self.resolve_expr(expr)
+    fn visit_use(&mut self, stmt: &Use<Result<Object>>) -> Result<Object> {
+        let (_, expr) = stmt.extract();
+        self.resolve_expr(expr)?;
+        let Some(module) = stmt.get_module() else {
+            return Ok(Object::Nil);
+        };
+        let mut module_types = self.resolve_module(module)?;
+
+        let bindings = match stmt.get_import() {
+            Import::All => vec![],
+            Import::Module(name) => vec![name.clone()],
+            Import::Names(names) => {
+                let exported = Exporter::exported_names(module);
+                if let Some(name) = names.iter().find(|name| {
+                    !exported
+                        .iter()
+                        .any(|export| export.get_lexeme() == name.get_lexeme())
+                }) {
+                    return Err(ParserError::new(
+                        name.clone(),
+                        ParserErrorType::UnknownImport(
+                            name.get_lexeme().into(),
+                            Exporter::module_path(stmt)?,
+                        ),
+                    )
+                    .into());
+                }
+                names.clone()
+            }
+        };
+        for name in bindings {
+            if self.imported.contains(name.get_lexeme()) {
+                return Err(ParserError::new(
+                    name.clone(),
+                    ParserErrorType::DuplicateImport(name.get_lexeme().into()),
+                )
+                .into());
+            }
+            if self.top_level.contains(name.get_lexeme()) {
+                return Err(ParserError::new(
+                    name.clone(),
+                    ParserErrorType::ImportCollision(name.get_lexeme().into()),
+                )
+                .into());
+            }
+            self.declare(&name)?;
+            self.define(&name);
+            self.imported.insert(name.get_lexeme().to_string());
+            if let Some(variants) = module_types.enums.remove(name.get_lexeme()) {
+                self.current_types()
+                    .enums
+                    .insert(name.get_lexeme().to_string(), variants);
+            }
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_break(&mut self, stmt: &Break) -> Result<Object> {
+        if self.current_loop == LoopType::None {
+            return Err(
+                ParserError::new(stmt.get_token(), ParserErrorType::CantBreakOutsideLoop).into(),
+            );
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_continue(&mut self, stmt: &Continue) -> Result<Object> {
+        if self.current_loop == LoopType::None {
+            return Err(ParserError::new(
+                stmt.get_token(),
+                ParserErrorType::CantContinueOutsideLoop,
+            )
+            .into());
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_try(&mut self, stmt: &Try<Result<Object>>) -> Result<Object> {
+        let (body, catch, finally) = stmt.extract();
+        self.begin_scope();
+        self.resolve(body)?;
+        self.end_scope();
+
+        if let Some((name, handler)) = catch {
+            self.begin_scope();
+            if let Some(name) = name {
+                self.declare(name)?;
+                self.define(name);
+            }
+            self.resolve(handler)?;
+            self.end_scope();
+        }
+
+        if let Some(finally) = finally {
+            self.begin_scope();
+            self.resolve(finally)?;
+            self.end_scope();
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_throw(&mut self, stmt: &Throw<Result<Object>>) -> Result<Object> {
+        let (_, value) = stmt.extract();
+        self.resolve_expr(value)?;
+        Ok(Object::Nil)
     }
 }
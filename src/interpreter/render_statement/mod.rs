@@ -1,4 +1,5 @@
-use crate::interpreter::error::{InterpreterError, Result};
+use crate::interpreter::error::{InterpreterError, Result, RuntimeErrorType};
+use crate::interpreter::object::Object;
 use crate::interpreter::render_statement::uniform_generator::UniformValueWrapper;
 use crate::interpreter::render_statement::vertex::Vertex;
 use glium::glutin::surface::WindowSurface;
@@ -27,3 +28,27 @@ impl RenderStatement {
             .map_err(|e| InterpreterError::Custom(e.to_string()))
     }
 }
+
+/// The primitive a pipeline draws, from its `primitive` field: a
+/// `Primitive` value or its legacy string, `triangleStrip` when unset.
+pub fn pipeline_primitive(pipeline: &Object) -> Result<PrimitiveType> {
+    let name = match pipeline.get_field(Object::String("primitive".into())) {
+        Err(_) | Ok(Object::Nil) => return Ok(PrimitiveType::TriangleStrip),
+        Ok(Object::String(name)) => name,
+        Ok(Object::Variant(variant)) => match variant.to_engine_string("Primitive") {
+            Some(name) => name,
+            None => return Err(RuntimeErrorType::UnknownPrimitive(variant.to_string()).into()),
+        },
+        Ok(other) => return Err(RuntimeErrorType::UnknownPrimitive(other.to_string()).into()),
+    };
+    match name.as_str() {
+        "points" => Ok(PrimitiveType::Points),
+        "lines" => Ok(PrimitiveType::LinesList),
+        "lineStrip" => Ok(PrimitiveType::LineStrip),
+        "lineLoop" => Ok(PrimitiveType::LineLoop),
+        "triangleStrip" => Ok(PrimitiveType::TriangleStrip),
+        "triangles" => Ok(PrimitiveType::TrianglesList),
+        "triangleFan" => Ok(PrimitiveType::TriangleFan),
+        _ => Err(RuntimeErrorType::UnknownPrimitive(name).into()),
+    }
+}
@@ -99,9 +99,18 @@ impl ShaderGenerator {
                 UniformValueWrapper::Float(_) => {
                     shader.push_str(&format!("uniform float {};\n", name))
                 }
+                UniformValueWrapper::Vec2(_) => {
+                    shader.push_str(&format!("uniform vec2 {};\n", name))
+                }
                 UniformValueWrapper::Vec3(_) => {
                     shader.push_str(&format!("uniform vec3 {};\n", name))
                 }
+                UniformValueWrapper::Vec4(_) => {
+                    shader.push_str(&format!("uniform vec4 {};\n", name))
+                }
+                UniformValueWrapper::Mat3(_) => {
+                    shader.push_str(&format!("uniform mat3 {};\n", name))
+                }
                 UniformValueWrapper::Mat4(_) => {
                     shader.push_str(&format!("uniform mat4 {};\n", name))
                 }
@@ -1,21 +1,41 @@
-use crate::interpreter::error::{InterpreterError, Result};
+use crate::interpreter::error::{InterpreterError, Result, RuntimeErrorType};
 use crate::interpreter::object::Object;
+use crate::interpreter::object::freeze::Freezable;
+use crate::interpreter::object::math::Math;
 use crate::rc;
 use glium::glutin::surface::WindowSurface;
 use glium::texture::RawImage2d;
 use glium::{Display, Texture2d};
 use image::{DynamicImage, RgbaImage};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub enum UniformValueWrapper {
     Float(f32),
+    Vec2([f32; 2]),
     Vec3([f32; 3]),
+    Vec4([f32; 4]),
+    Mat3([[f32; 3]; 3]),
     Mat4([[f32; 4]; 4]),
     Sampler2D(Arc<DynamicImage>),
 }
 
+impl From<Math> for UniformValueWrapper {
+    fn from(value: Math) -> Self {
+        match value {
+            Math::Vec2(v) => UniformValueWrapper::Vec2(v.cast().unwrap().into()),
+            Math::Vec3(v) => UniformValueWrapper::Vec3(v.cast().unwrap().into()),
+            Math::Vec4(v) => UniformValueWrapper::Vec4(v.cast().unwrap().into()),
+            Math::Mat3(m) => UniformValueWrapper::Mat3(m.cast().unwrap().into()),
+            Math::Mat4(m) => UniformValueWrapper::Mat4(m.cast().unwrap().into()),
+            Math::Quat(q) => {
+                UniformValueWrapper::Vec4([q.v.x as f32, q.v.y as f32, q.v.z as f32, q.s as f32])
+            }
+        }
+    }
+}
+
 pub struct UniformGenerator {
     default: Object,
     tex_buffer: Vec<Arc<DynamicImage>>,
@@ -31,25 +51,25 @@ impl UniformGenerator {
 
     fn default_common_uniforms() -> Object {
         let identity_matrix = vec![
-            Object::List(rc!(RwLock::new(vec![
+            Object::List(rc!(Freezable::new(vec![
                 Object::Number(1.0),
                 Object::Number(0.0),
                 Object::Number(0.0),
                 Object::Number(0.0),
             ]))),
-            Object::List(rc!(RwLock::new(vec![
+            Object::List(rc!(Freezable::new(vec![
                 Object::Number(0.0),
                 Object::Number(1.0),
                 Object::Number(0.0),
                 Object::Number(0.0),
             ]))),
-            Object::List(rc!(RwLock::new(vec![
+            Object::List(rc!(Freezable::new(vec![
                 Object::Number(0.0),
                 Object::Number(0.0),
                 Object::Number(1.0),
                 Object::Number(0.0),
             ]))),
-            Object::List(rc!(RwLock::new(vec![
+            Object::List(rc!(Freezable::new(vec![
                 Object::Number(0.0),
                 Object::Number(0.0),
                 Object::Number(0.0),
@@ -57,28 +77,28 @@ impl UniformGenerator {
             ]))),
         ];
 
-        let matrix = Object::Dictionary(Arc::new(RwLock::new(HashMap::from([
+        let matrix = Object::Dictionary(Arc::new(Freezable::new(HashMap::from([
             ("type".to_string(), Object::String("mat4".to_string())),
             (
                 "value".to_string(),
-                Object::List(rc!(RwLock::new(identity_matrix.clone()))),
+                Object::List(rc!(Freezable::new(identity_matrix.clone()))),
             ),
         ]))));
-        Object::Dictionary(Arc::new(RwLock::new(HashMap::from([
+        Object::Dictionary(Arc::new(Freezable::new(HashMap::from([
             (
                 "time".to_string(),
-                Object::Dictionary(Arc::new(RwLock::new(HashMap::from([
+                Object::Dictionary(Arc::new(Freezable::new(HashMap::from([
                     ("type".to_string(), Object::String("float".to_string())),
                     ("value".to_string(), Object::Number(0.0)),
                 ])))),
             ),
             (
                 "color".to_string(),
-                Object::Dictionary(Arc::new(RwLock::new(HashMap::from([
+                Object::Dictionary(Arc::new(Freezable::new(HashMap::from([
                     ("type".to_string(), Object::String("vec3".to_string())),
                     (
                         "value".to_string(),
-                        Object::List(rc!(RwLock::new(vec![
+                        Object::List(rc!(Freezable::new(vec![
                             Object::Number(1.0),
                             Object::Number(1.0),
                             Object::Number(1.0),
@@ -91,11 +111,11 @@ impl UniformGenerator {
             ("projection".to_string(), matrix.clone()),
             (
                 "light_position".to_string(),
-                Object::Dictionary(Arc::new(RwLock::new(HashMap::from([
+                Object::Dictionary(Arc::new(Freezable::new(HashMap::from([
                     ("type".to_string(), Object::String("vec3".to_string())),
                     (
                         "value".to_string(),
-                        Object::List(rc!(RwLock::new(vec![
+                        Object::List(rc!(Freezable::new(vec![
                             Object::Number(0.0),
                             Object::Number(0.0),
                             Object::Number(5.0),
@@ -128,135 +148,20 @@ impl UniformGenerator {
         merged_uniforms.extend(user_uniforms.iter().map(|(k, v)| (k.clone(), v.clone())));
 
         for (name, uniform) in merged_uniforms.iter() {
-            let uniform_dict = match uniform {
-                Object::Dictionary(dict) => dict,
-                _ => {
-                    return Err(InterpreterError::Custom(
-                        "Uniform must be a dictionary".into(),
-                    ));
-                }
-            }
-            .read()
-            .unwrap();
-
-            let uniform_type = match uniform_dict.get("type") {
-                Some(Object::String(s)) => s,
-                _ => {
-                    return Err(InterpreterError::Custom(
-                        "Uniform type must be a string".into(),
-                    ));
-                }
-            };
-
-            let value = match uniform_dict.get("value") {
-                Some(v) => v,
-                _ => return Err(InterpreterError::Custom("Uniform value required".into())),
-            };
-
-            match uniform_type.as_str() {
-                "float" => {
-                    let float_value = match value {
-                        Object::Number(n) => *n as f32,
-                        _ => {
-                            return Err(InterpreterError::Custom(
-                                "Float value must be a number".into(),
-                            ));
-                        }
-                    };
-                    uniform_values.insert(name.clone(), UniformValueWrapper::Float(float_value));
-                }
-                "vec3" => {
-                    let vec3_value = match value {
-                        Object::List(list) if list.read().unwrap().len() == 3 => {
-                            let mut arr = [0.0f32; 3];
-                            for (i, item) in list.read().unwrap().iter().enumerate() {
-                                arr[i] = match item {
-                                    Object::Number(n) => *n as f32,
-                                    _ => {
-                                        return Err(InterpreterError::Custom(
-                                            "Vec3 value must be numbers".into(),
-                                        ));
-                                    }
-                                };
-                            }
-                            arr
-                        }
-                        _ => {
-                            return Err(InterpreterError::Custom(
-                                "Vec3 value must be a list of 3 numbers".into(),
-                            ));
-                        }
-                    };
-                    uniform_values.insert(name.clone(), UniformValueWrapper::Vec3(vec3_value));
-                }
-                "mat4" => {
-                    let mat4_value = match value {
-                        Object::List(list) => {
-                            //println!("list: {:?}", list);
-                            let mut arr = [[0.0f32; 4]; 4];
-                            for (i, item) in list.read().unwrap().iter().enumerate() {
-                                arr[i] = match item {
-                                    Object::List(n) => {
-                                        let mut buffer = [0.0f32; 4];
-                                        for (i, obj) in n.read().unwrap().iter().enumerate() {
-                                            buffer[i] = if let Object::Number(n) = obj {
-                                                *n as f32
-                                            } else {
-                                                0.0f32
-                                            };
-                                        }
-                                        //println!("{:?}", buffer);
-                                        buffer
-                                    }
-                                    _ => {
-                                        return Err(InterpreterError::Custom(
-                                            "Mat4 value must be list".into(),
-                                        ));
-                                    }
-                                };
-                            }
-                            arr
-                        }
-                        _ => {
-                            return Err(InterpreterError::Custom(
-                                "Mat4 value must be a matrix 4x4".into(),
-                            ));
-                        }
-                    };
-
-                    //println!("mat4: {:?}", mat4_value);
-
-                    uniform_values.insert(name.clone(), UniformValueWrapper::Mat4(mat4_value));
-                }
-                "sampler2D" => {
-                    let Object::NativeObject(native) = value.clone() else {
-                        panic!("Expected Native object")
-                    };
-                    if let Ok(image) = native.extract().downcast::<Arc<DynamicImage>>() {
-                        if let Some(image) = self
-                            .tex_buffer
-                            .iter()
-                            .find(|image_| image_.as_ref().eq(&image))
-                        {
-                            uniform_values.insert(
-                                name.clone(),
-                                UniformValueWrapper::Sampler2D(image.clone()),
-                            );
-                        } else {
-                            let image = *image;
+            let value = match uniform_value(name, uniform)? {
+                // Each image is uploaded once, later uniforms share it.
+                UniformValueWrapper::Sampler2D(image) => {
+                    match self.tex_buffer.iter().find(|buffered| **buffered == image) {
+                        Some(buffered) => UniformValueWrapper::Sampler2D(buffered.clone()),
+                        None => {
                             self.tex_buffer.push(image.clone());
-                            uniform_values
-                                .insert(name.clone(), UniformValueWrapper::Sampler2D(image));
+                            UniformValueWrapper::Sampler2D(image)
                         }
                     }
                 }
-                _ => {
-                    return Err(InterpreterError::Custom(format!(
-                        "Unsupported uniform type: {}",
-                        uniform_type
-                    )));
-                }
-            }
+                value => value,
+            };
+            uniform_values.insert(name.clone(), value);
         }
 
         //println!("----UNIFORMS: {:?}", uniform_values);
@@ -264,6 +169,125 @@ impl UniformGenerator {
         Ok(uniform_values)
     }
 }
+/// Checks the uniforms of a pipeline the way `generate_uniforms` reads them.
+pub fn validate_uniforms(uniforms: &Object) -> Result<()> {
+    let Object::Dictionary(uniforms) = uniforms.inner() else {
+        return Err(RuntimeErrorType::InvalidArgument(
+            "a dictionary of uniforms".into(),
+            uniforms.get_type(),
+        )
+        .into());
+    };
+    for (name, uniform) in uniforms.read().unwrap().iter() {
+        uniform_value(name, uniform)?;
+    }
+    Ok(())
+}
+
+/// Converts a uniform given as a math value or as a `{type, value}`
+/// dictionary, whose value may also be a list of numbers or of rows.
+fn uniform_value(name: &str, uniform: &Object) -> Result<UniformValueWrapper> {
+    let invalid = |reason: String| -> InterpreterError {
+        RuntimeErrorType::InvalidUniform(name.to_string(), reason).into()
+    };
+    let uniform_dict = match uniform.inner() {
+        Object::Math(value) => return Ok((*value).into()),
+        Object::Dictionary(dict) => dict.read().unwrap(),
+        _ => return Err(invalid("must be a dictionary".into())),
+    };
+
+    let uniform_type = match uniform_dict.get("type") {
+        Some(Object::String(s)) => Some(s.clone()),
+        Some(Object::Variant(variant)) => variant.to_engine_string("Uniform"),
+        _ => None,
+    };
+    let Some(uniform_type) = uniform_type else {
+        return Err(invalid("needs a type, a string or a Uniform value".into()));
+    };
+    let Some(value) = uniform_dict.get("value") else {
+        return Err(invalid("needs a value".into()));
+    };
+
+    if let Object::Math(value) = value.inner() {
+        // A quaternion is uploaded as a vec4, as a bare `quat(..)` uniform is.
+        let uploaded_as = match value {
+            Math::Quat(_) => "vec4",
+            value => value.get_type(),
+        };
+        if uploaded_as != uniform_type {
+            return Err(invalid(format!(
+                "is declared as {} but holds a {}",
+                uniform_type,
+                value.get_type()
+            )));
+        }
+        return Ok((*value).into());
+    }
+
+    let converted = match uniform_type.as_str() {
+        "float" => match value.inner() {
+            Object::Number(n) => Some(UniformValueWrapper::Float(*n as f32)),
+            _ => None,
+        },
+        "vec2" => numbers(value).map(UniformValueWrapper::Vec2),
+        "vec3" => numbers(value).map(UniformValueWrapper::Vec3),
+        "vec4" => numbers(value).map(UniformValueWrapper::Vec4),
+        "mat3" => rows(value).map(UniformValueWrapper::Mat3),
+        "mat4" => rows(value).map(UniformValueWrapper::Mat4),
+        "sampler2D" => match value.inner() {
+            Object::NativeObject(native) => native
+                .clone()
+                .extract()
+                .downcast::<Arc<DynamicImage>>()
+                .ok()
+                .map(|image| UniformValueWrapper::Sampler2D(*image)),
+            _ => None,
+        },
+        _ => return Err(invalid(format!("has unsupported type '{}'", uniform_type))),
+    };
+    converted.ok_or_else(|| {
+        invalid(format!(
+            "holds '{}', which is not a {}",
+            value, uniform_type
+        ))
+    })
+}
+
+/// A list of exactly `N` numbers.
+fn numbers<const N: usize>(value: &Object) -> Option<[f32; N]> {
+    let Object::List(list) = value.inner() else {
+        return None;
+    };
+    let list = list.read().unwrap();
+    if list.len() != N {
+        return None;
+    }
+    let mut arr = [0.0f32; N];
+    for (slot, item) in arr.iter_mut().zip(list.iter()) {
+        let Object::Number(n) = item.inner() else {
+            return None;
+        };
+        *slot = *n as f32;
+    }
+    Some(arr)
+}
+
+/// A list of `N` rows of `N` numbers each.
+fn rows<const N: usize>(value: &Object) -> Option<[[f32; N]; N]> {
+    let Object::List(list) = value.inner() else {
+        return None;
+    };
+    let list = list.read().unwrap();
+    if list.len() != N {
+        return None;
+    }
+    let mut arr = [[0.0f32; N]; N];
+    for (row, item) in arr.iter_mut().zip(list.iter()) {
+        *row = numbers(item)?;
+    }
+    Some(arr)
+}
+
 pub fn load_texture(display: &Display<WindowSurface>, image: RgbaImage) -> Result<Texture2d> {
     let image_dimensions = image.dimensions();
     let image = RawImage2d::from_raw_rgba_reversed(&image.into_raw(), image_dimensions);
@@ -40,6 +40,8 @@ impl From<ScannerError> for InterpreterError {
 pub enum ScannerErrorType {
     UnexpectedCharacter(char),
     UnterminatedString,
+    InvalidEscapeSequence(char),
+    InvalidUnicodeEscape,
 }
 
 impl Display for ScannerErrorType {
@@ -47,6 +49,12 @@ impl Display for ScannerErrorType {
         match self {
             ScannerErrorType::UnexpectedCharacter(ch) => write!(f, "Unexpected character '{ch}'"),
             ScannerErrorType::UnterminatedString => write!(f, "Unterminated string"),
+            ScannerErrorType::InvalidEscapeSequence(ch) => {
+                write!(f, "Invalid escape sequence '\\{ch}'")
+            }
+            ScannerErrorType::InvalidUnicodeEscape => {
+                write!(f, "Invalid unicode escape, expected '\\u{{XXXX}}'")
+            }
         }
     }
 }
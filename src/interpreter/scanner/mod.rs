@@ -17,6 +17,9 @@ pub struct Scanner {
     line: usize,
     pos_in_line: usize,
     keywords: HashMap<String, TokenType>,
+    /// Open `${` interpolations: unmatched '{' inside the embedded expression
+    /// and the position of the string literal it belongs to.
+    interpolations: Vec<(usize, usize, usize)>,
 }
 
 impl Scanner {
@@ -33,10 +36,20 @@ impl Scanner {
 
         keywords.insert("for".into(), TokenType::For);
         keywords.insert("while".into(), TokenType::While);
+        keywords.insert("break".into(), TokenType::Break);
+        keywords.insert("continue".into(), TokenType::Continue);
+        keywords.insert("match".into(), TokenType::Match);
+        keywords.insert("try".into(), TokenType::Try);
+        keywords.insert("catch".into(), TokenType::Catch);
+        keywords.insert("finally".into(), TokenType::Finally);
+        keywords.insert("throw".into(), TokenType::Throw);
 
         keywords.insert("fun".into(), TokenType::Fun);
         keywords.insert("class".into(), TokenType::Class);
+        keywords.insert("trait".into(), TokenType::Trait);
+        keywords.insert("enum".into(), TokenType::Enum);
         keywords.insert("let".into(), TokenType::Let);
+        keywords.insert("const".into(), TokenType::Const);
 
         keywords.insert("nil".into(), TokenType::Nil);
 
@@ -57,6 +70,7 @@ impl Scanner {
             line: 1,
             pos_in_line: 1,
             keywords,
+            interpolations: vec![],
         }
     }
 
@@ -65,6 +79,11 @@ impl Scanner {
             self.start = self.current;
             self.scan_token()?;
         }
+        if let Some((_, line, pos)) = self.interpolations.last() {
+            return Err(
+                ScannerError::new(*line, *pos, ScannerErrorType::UnterminatedString).into(),
+            );
+        }
         self.tokens.push(Token::new(
             TokenType::Eof,
             "",
@@ -87,34 +106,77 @@ impl Scanner {
             ')' => self.add_token(TokenType::RightParen, None),
             '[' => self.add_token(TokenType::LeftBracket, None),
             ']' => self.add_token(TokenType::RightBracket, None),
-            '{' => self.add_token(TokenType::LeftBrace, None),
-            '}' => self.add_token(TokenType::RightBrace, None),
+            '{' => {
+                if let Some((braces, _, _)) = self.interpolations.last_mut() {
+                    *braces += 1;
+                }
+                self.add_token(TokenType::LeftBrace, None)
+            }
+            '}' => match self.interpolations.last_mut() {
+                Some((0, line, pos)) => {
+                    let (line, pos) = (*line, *pos);
+                    self.interpolations.pop();
+                    self.string_segment(line, pos, true)?
+                }
+                Some((braces, _, _)) => {
+                    *braces -= 1;
+                    self.add_token(TokenType::RightBrace, None)
+                }
+                None => self.add_token(TokenType::RightBrace, None),
+            },
             ',' => self.add_token(TokenType::Comma, None),
             ':' => self.add_token(TokenType::Colon, None),
+            '.' if self.find_match('.') => {
+                if self.find_match('.') {
+                    self.add_token(TokenType::DotDotDot, None)
+                } else {
+                    self.add_token(TokenType::DotDot, None)
+                }
+            }
             '.' => self.add_token(TokenType::Dot, None),
+            '?' if self.find_match('?') => self.add_token(TokenType::QuestionQuestion, None),
+            '?' if self.find_match('.') => self.add_token(TokenType::QuestionDot, None),
+            '?' => self.add_token(TokenType::Question, None),
+            '-' if self.find_match('-') => self.add_token(TokenType::MinusMinus, None),
+            '-' if self.find_match('=') => self.add_token(TokenType::MinusEqual, None),
             '-' => self.add_token(TokenType::Minus, None),
+            '+' if self.find_match('+') => self.add_token(TokenType::PlusPlus, None),
+            '+' if self.find_match('=') => self.add_token(TokenType::PlusEqual, None),
             '+' => self.add_token(TokenType::Plus, None),
             ';' => self.add_token(TokenType::Semicolon, None),
+            '*' if self.find_match('*') => self.add_token(TokenType::StarStar, None),
+            '*' if self.find_match('=') => self.add_token(TokenType::StarEqual, None),
             '*' => self.add_token(TokenType::Star, None),
+            '%' if self.find_match('=') => self.add_token(TokenType::PercentEqual, None),
+            '%' => self.add_token(TokenType::Percent, None),
+            '&' => self.add_token(TokenType::Ampersand, None),
+            '|' => self.add_token(TokenType::Pipe, None),
+            '^' => self.add_token(TokenType::Caret, None),
+            '~' => self.add_token(TokenType::Tilde, None),
             '!' if self.find_match('=') => self.add_token(TokenType::BangEqual, None),
             '!' => self.add_token(TokenType::Bang, None),
             '=' if self.find_match('=') => self.add_token(TokenType::EqualEqual, None),
+            '=' if self.find_match('>') => self.add_token(TokenType::FatArrow, None),
             '=' => self.add_token(TokenType::Equal, None),
             '<' if self.find_match('=') => self.add_token(TokenType::LessEqual, None),
+            '<' if self.find_match('<') => self.add_token(TokenType::LessLess, None),
             '<' => self.add_token(TokenType::Less, None),
             '>' if self.find_match('=') => self.add_token(TokenType::GreaterEqual, None),
+            '>' if self.find_match('>') => self.add_token(TokenType::GreaterGreater, None),
             '>' => self.add_token(TokenType::Greater, None),
             '/' if self.find_match('/') => {
                 while self.peek() != '\n' && !self.is_at_end() {
                     self.advance();
                 }
             }
+            '/' if self.find_match('=') => self.add_token(TokenType::SlashEqual, None),
             '/' => self.add_token(TokenType::Slash, None),
             ' ' | '\r' | '\t' => {}
             '\n' => {
                 self.line += 1;
                 self.pos_in_line = 1;
             }
+            '"' if self.peek() == '"' && self.peek_next() == '"' => self.raw_string()?,
             '"' => self.string()?,
             _ => {
                 if self.is_digit(c) {
@@ -160,6 +222,7 @@ impl Scanner {
             return false;
         }
         self.current += 1;
+        self.pos_in_line += 1;
         true
     }
 
@@ -170,30 +233,153 @@ impl Scanner {
         self.source.chars().nth(self.current).unwrap()
     }
 
+    fn add_token_at(&mut self, ty: TokenType, lit: Option<Object>, line: usize, pos: usize) {
+        let text = self.source[self.start..self.current].to_string();
+        self.tokens.push(Token::new(ty, &text, lit, line, pos));
+    }
+
+    fn newline(&mut self) {
+        self.line += 1;
+        self.pos_in_line = 1;
+    }
+
+    fn peek_at(&self, offset: usize) -> char {
+        self.source
+            .chars()
+            .nth(self.current + offset)
+            .unwrap_or('\0')
+    }
+
     fn string(&mut self) -> Result<()> {
-        let mut ch = self.peek();
-        while ch != '"' && !self.is_at_end() {
-            if ch == '\n' {
-                self.line += 1;
-                self.pos_in_line = 1;
+        let (line, pos) = (self.line, self.pos_in_line - 1);
+        self.string_segment(line, pos, false)
+    }
+
+    /// Scans string content up to the closing quote or the next `${`. The
+    /// latter emits an interpolation token and leaves the embedded
+    /// expression to the regular scanner until its closing '}', which resumes
+    /// the literal as a `continuation` segment.
+    fn string_segment(&mut self, line: usize, pos: usize, continuation: bool) -> Result<()> {
+        let (segment_line, segment_pos) = (self.line, self.pos_in_line - 1);
+        let mut value = String::new();
+        loop {
+            if self.is_at_end() {
+                return Err(
+                    ScannerError::new(line, pos, ScannerErrorType::UnterminatedString).into(),
+                );
+            }
+            match self.advance() {
+                '"' => break,
+                '$' if self.find_match('{') => {
+                    let ty = if continuation {
+                        TokenType::InterpolationPart
+                    } else {
+                        TokenType::Interpolation
+                    };
+                    self.add_token_at(ty, Some(Object::String(value)), segment_line, segment_pos);
+                    self.interpolations.push((0, line, pos));
+                    return Ok(());
+                }
+                '\n' => {
+                    self.newline();
+                    value.push('\n');
+                }
+                '\\' => value.push(self.escape()?),
+                ch => value.push(ch),
             }
-            self.advance();
-            ch = self.peek();
-            //println!("character: {} {}", ch, ch_);
         }
 
+        let ty = if continuation {
+            TokenType::InterpolationEnd
+        } else {
+            TokenType::String
+        };
+        self.add_token_at(ty, Some(Object::String(value)), segment_line, segment_pos);
+        Ok(())
+    }
+
+    fn escape(&mut self) -> Result<char> {
+        let pos = self.pos_in_line - 1;
         if self.is_at_end() {
-            return Err(ScannerError::new(
-                self.line,
-                self.pos_in_line,
-                ScannerErrorType::UnterminatedString,
-            )
-            .into());
+            return Err(
+                ScannerError::new(self.line, pos, ScannerErrorType::UnterminatedString).into(),
+            );
+        }
+        let ch = match self.advance() {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            '"' => '"',
+            '\\' => '\\',
+            '$' => '$',
+            'u' => return self.unicode_escape(pos),
+            ch => {
+                return Err(ScannerError::new(
+                    self.line,
+                    pos,
+                    ScannerErrorType::InvalidEscapeSequence(ch),
+                )
+                .into());
+            }
+        };
+        Ok(ch)
+    }
+
+    fn unicode_escape(&mut self, pos: usize) -> Result<char> {
+        let error = ScannerError::new(self.line, pos, ScannerErrorType::InvalidUnicodeEscape);
+        if !self.find_match('{') {
+            return Err(error.into());
         }
 
+        let mut digits = String::new();
+        while self.peek().is_ascii_hexdigit() && digits.len() < 6 {
+            digits.push(self.advance());
+        }
+
+        if !self.find_match('}') {
+            return Err(error.into());
+        }
+
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| error.into())
+    }
+
+    /// `"""..."""`: spans lines and keeps its content verbatim, without
+    /// escape processing. A newline right after the opening quotes is dropped.
+    fn raw_string(&mut self) -> Result<()> {
+        let (line, pos) = (self.line, self.pos_in_line - 1);
+        self.advance();
         self.advance();
-        let value = self.source[self.start + 1..self.current - 1].replace("\\n", "\n");
-        self.add_token(TokenType::String, Some(Object::String(value)));
+
+        if self.peek() == '\n' {
+            self.advance();
+            self.newline();
+        }
+
+        let mut value = String::new();
+        loop {
+            if self.is_at_end() {
+                return Err(
+                    ScannerError::new(line, pos, ScannerErrorType::UnterminatedString).into(),
+                );
+            }
+            if self.peek() == '"' && self.peek_at(1) == '"' && self.peek_at(2) == '"' {
+                self.advance();
+                self.advance();
+                self.advance();
+                break;
+            }
+            let ch = self.advance();
+            if ch == '\n' {
+                self.newline();
+            }
+            value.push(ch);
+        }
+
+        self.add_token_at(TokenType::String, Some(Object::String(value)), line, pos);
         Ok(())
     }
 
@@ -11,6 +11,12 @@ pub enum TokenType {
     Semicolon,
     Slash,
     Star,
+    Percent,
+    Ampersand,
+    Pipe,
+    Caret,
+    Tilde,
+    Question,
 
     // One or two character tokens.
     Bang,
@@ -21,10 +27,28 @@ pub enum TokenType {
     GreaterEqual,
     Less,
     LessEqual,
+    StarStar,
+    LessLess,
+    GreaterGreater,
+    PlusEqual,
+    MinusEqual,
+    StarEqual,
+    SlashEqual,
+    PercentEqual,
+    PlusPlus,
+    MinusMinus,
+    DotDot,
+    DotDotDot,
+    QuestionDot,
+    QuestionQuestion,
+    FatArrow,
 
     // Literals.
     Identifier,
     String,
+    Interpolation,
+    InterpolationPart,
+    InterpolationEnd,
     Number,
 
     // Keywords.
@@ -44,6 +68,16 @@ pub enum TokenType {
     True,
     Let,
     While,
+    Break,
+    Continue,
+    Match,
+    Try,
+    Catch,
+    Finally,
+    Throw,
+    Trait,
+    Enum,
+    Const,
 
     Eof,
     Use,
@@ -1,9 +1,10 @@
 use crate::cli::Cli;
 use clap::Parser;
-use glium::backend::glutin::SimpleWindowBuilder;
 use glium::winit::event_loop::EventLoopBuilder;
 use std::sync::Arc;
 use yun_gl_lib::app::App;
+use yun_gl_lib::app::manifest::MANIFEST_NAME;
+use yun_gl_lib::app::window;
 use yun_gl_lib::interpreter::error::Result;
 use yun_gl_lib::interpreter::event::InterpreterEvent;
 use yun_gl_lib::rc;
@@ -12,18 +13,21 @@ mod cli;
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    let manifest = cli.get_manifest()?;
+    let Some(path) = manifest.entry.clone() else {
+        return Err(format!("No script given and no entry in {}", MANIFEST_NAME).into());
+    };
 
     let event_loop = EventLoopBuilder::<InterpreterEvent>::default().build()?;
 
-    let (window, display) = SimpleWindowBuilder::new()
-        .with_title("App")
-        .build(&event_loop);
+    let (window, display) = window::build(&event_loop, &manifest.window)?;
 
     let mut app = App::new(
         rc!(window),
         rc!(display),
         event_loop.create_proxy(),
-        cli.get_path().clone(),
+        path,
+        &manifest,
     );
     Ok(event_loop.run_app(&mut app)?)
 }